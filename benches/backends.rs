@@ -0,0 +1,132 @@
+//! Construction and cloning of `StringHolda` types over each storage backend.
+//!
+//! Run with `cargo bench --bench backends --all-features`.
+
+use std::hint::black_box;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use compact_str::CompactString;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use holda::StringHolda;
+use holda::Symbol;
+use smol_str::SmolStr;
+
+#[derive(StringHolda)]
+struct OnString {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+struct OnBox {
+    inner: Box<str>,
+}
+
+#[derive(StringHolda)]
+struct OnRc {
+    inner: Rc<str>,
+}
+
+#[derive(StringHolda)]
+struct OnArc {
+    inner: Arc<str>,
+}
+
+#[derive(StringHolda)]
+struct OnSmolStr {
+    inner: SmolStr,
+}
+
+#[derive(StringHolda)]
+struct OnCompactString {
+    inner: CompactString,
+}
+
+#[derive(StringHolda)]
+struct OnInline {
+    inner: heapless::String<64>,
+}
+
+#[derive(StringHolda)]
+#[holda(interned)]
+struct OnSymbol {
+    inner: Symbol,
+}
+
+/// One identifier that fits inline in every small-string backend and one
+/// that does not.
+const INPUTS: &[(&str, &str)] = &[
+    ("short", "user_name_42"),
+    ("long", "a_much_longer_identifier_that_spills_to_the_heap"),
+];
+
+macro_rules! bench_backends {
+    ($group:expr, $input:expr, $name:expr, [$($backend:ident),*]) => {$(
+        $group.bench_with_input(
+            BenchmarkId::new(stringify!($backend), $name),
+            $input,
+            |b, input| b.iter(|| $backend::try_from(black_box(*input)).unwrap()),
+        );
+    )*};
+}
+
+fn from_str(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_str");
+    for (name, input) in INPUTS {
+        bench_backends!(
+            group,
+            input,
+            name,
+            [
+                OnString,
+                OnBox,
+                OnRc,
+                OnArc,
+                OnSmolStr,
+                OnCompactString,
+                OnInline,
+                OnSymbol
+            ]
+        );
+    }
+    group.finish();
+}
+
+macro_rules! bench_clones {
+    ($group:expr, $input:expr, $name:expr, [$($backend:ident),*]) => {$(
+        let value = $backend::try_from(*$input).unwrap();
+        $group.bench_with_input(
+            BenchmarkId::new(stringify!($backend), $name),
+            &value,
+            |b, value| b.iter(|| black_box(value).clone()),
+        );
+    )*};
+}
+
+fn clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone");
+    for (name, input) in INPUTS {
+        bench_clones!(
+            group,
+            input,
+            name,
+            [
+                OnString,
+                OnBox,
+                OnRc,
+                OnArc,
+                OnSmolStr,
+                OnCompactString,
+                OnInline,
+                OnSymbol
+            ]
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, from_str, clone);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+//! Holda types in a `no_std` crate, with both heap-allocated and
+//! fixed-capacity inners.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use holda::Holda;
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(non_empty, charset = "a-z0-9_", from_str_error = "validation")]
+pub struct DeviceName {
+    inner: heapless::String<8>,
+}
+
+#[derive(StringHolda)]
+#[holda(from_str_error = "validation")]
+pub struct Label {
+    inner: heapless::String<4>,
+}
+
+#[derive(StringHolda)]
+#[holda(normalize(trim), case_insensitive = "ascii")]
+pub struct Topic {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(FromStr, range = 1..=100)]
+pub struct Percent {
+    inner: u8,
+}
@@ -0,0 +1,46 @@
+use holda::ValidationErrorKind;
+use holda::holda_lit;
+use holda_no_std::DeviceName;
+use holda_no_std::Label;
+use holda_no_std::Percent;
+use holda_no_std::Topic;
+
+#[test]
+fn test_heapless_inner() {
+    let name = DeviceName::try_from("sensor_1").unwrap();
+    assert_eq!(&**name, "sensor_1");
+    assert_eq!(name.to_string(), "sensor_1");
+
+    let err = DeviceName::try_from("sensor_12").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ValidationErrorKind::CapacityExceeded {
+            capacity: 8,
+            actual: 9
+        }
+    );
+    assert!(DeviceName::try_from("Sensor").is_err());
+    assert!("".parse::<DeviceName>().is_err());
+    assert_eq!(*"pump".parse::<DeviceName>().unwrap(), "pump");
+}
+
+#[test]
+fn test_heapless_inner_without_validators() {
+    let label = Label::try_from("abcd").unwrap();
+    assert_eq!(label.as_str(), "abcd");
+    assert!(Label::try_from("abcde").is_err());
+    assert!("abcde".parse::<Label>().is_err());
+}
+
+#[test]
+fn test_heapless_literal() {
+    let name = holda_lit!(DeviceName, "pump_2");
+    assert_eq!(&**name, "pump_2");
+}
+
+#[test]
+fn test_alloc_inner() {
+    let topic = Topic::new(" Alerts ");
+    assert_eq!(topic, Topic::from("ALERTS"));
+    assert_eq!(*"42".parse::<Percent>().unwrap(), 42);
+}
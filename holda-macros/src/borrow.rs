@@ -0,0 +1,63 @@
+//! `#[holda(Borrow)]` and, with the `equivalent` feature, `Equivalent` impls
+//! for case-insensitive types, whose hashing `Borrow` would contradict.
+
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+use syn::Type;
+
+use crate::CaseMode;
+
+/// `Borrow<Inner>` and, for StringHolda, `Borrow<str>`.
+///
+/// Only sound while `Eq`, `Ord` and `Hash` delegate to the inner value, so
+/// the caller rejects `Borrow` on case-insensitive types.
+pub fn borrow_impls(
+    struct_name: &Ident,
+    inner_ident: &Ident,
+    inner_type: &Type,
+    is_string: bool,
+) -> TokenStream {
+    let str_impl = if is_string {
+        quote! {
+            impl ::core::borrow::Borrow<str> for #struct_name {
+                fn borrow(&self) -> &str {
+                    ::core::convert::AsRef::<str>::as_ref(&self.#inner_ident)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    quote! {
+        impl ::core::borrow::Borrow<#inner_type> for #struct_name {
+            fn borrow(&self) -> &#inner_type {
+                &self.#inner_ident
+            }
+        }
+
+        #str_impl
+    }
+}
+
+/// `Equivalent<Self>` for the query type hashing like `mode`, so maps keyed
+/// by a case-insensitive type can be queried with a borrowed `str`.
+pub fn equivalent_impl(
+    krate: &Path,
+    struct_name: &Ident,
+    inner_ident: &Ident,
+    mode: CaseMode,
+) -> TokenStream {
+    let (query, eq) = match mode {
+        CaseMode::Ascii => (quote! { AsciiCaseInsensitive }, quote! { eq_ascii }),
+        CaseMode::Unicode => (quote! { UnicodeCaseInsensitive }, quote! { eq_unicode }),
+    };
+    quote! {
+        impl #krate::__private::equivalent::Equivalent<#struct_name> for #krate::#query {
+            fn equivalent(&self, key: &#struct_name) -> bool {
+                #krate::__private::case::#eq(self.as_str(), ::core::convert::AsRef::<str>::as_ref(&key.#inner_ident))
+            }
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! `#[holda(borrowed = NameRef)]`: an unsized `str` counterpart of a
+//! StringHolda, related to it like `Path` to `PathBuf`.
+
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+use syn::Type;
+use syn::Visibility;
+
+pub struct Borrowed<'a> {
+    pub krate: &'a Path,
+    pub struct_name: &'a Ident,
+    pub ref_name: &'a Ident,
+    pub vis: &'a Visibility,
+    pub inner_ident: &'a Ident,
+    pub inner_type: &'a Type,
+    pub constructor_vis: &'a Visibility,
+    pub validated: bool,
+    pub normalized: bool,
+    pub skip_display: bool,
+    pub skip_eq: bool,
+    pub skip_ord: bool,
+    pub skip_hash: bool,
+    pub skip_serde: bool,
+    pub skip_from: bool,
+    pub redact_debug: bool,
+}
+
+impl Borrowed<'_> {
+    /// `Deref<Target = NameRef>` for the owned type, replacing the one to the
+    /// inner type.
+    pub fn deref_impl(&self) -> TokenStream {
+        let struct_name = self.struct_name;
+        let ref_name = self.ref_name;
+        let inner_ident = self.inner_ident;
+        quote! {
+            impl ::core::ops::Deref for #struct_name {
+                type Target = #ref_name;
+
+                fn deref(&self) -> &Self::Target {
+                    #ref_name::__holda_from_str(::core::convert::AsRef::<str>::as_ref(&self.#inner_ident))
+                }
+            }
+        }
+    }
+
+    /// The unsized type and its plumbing to and from the owned type.
+    pub fn impls(&self) -> TokenStream {
+        let krate = self.krate;
+        let struct_name = self.struct_name;
+        let ref_name = self.ref_name;
+        let vis = self.vis;
+        let inner_ident = self.inner_ident;
+        let inner_type = self.inner_type;
+        let doc = format!("Borrowed form of [`{struct_name}`], as `str` is of `String`.");
+
+        // Every `&NameRef` is a borrowed, already normalized `NameRef`, so the
+        // constructors only exist where normalization has nothing to do.
+        let constructor = if self.normalized {
+            quote! {}
+        } else if self.validated {
+            let constructor_vis = self.constructor_vis;
+            quote! {
+                /// Views `value` as this type without copying it, if it
+                /// passes validation.
+                #constructor_vis const fn try_new(value: &str) -> ::core::result::Result<&Self, #krate::ValidationError> {
+                    match #struct_name::__holda_check(value) {
+                        ::core::result::Result::Ok(()) => ::core::result::Result::Ok(Self::__holda_from_str(value)),
+                        ::core::result::Result::Err(kind) => ::core::result::Result::Err(
+                            #krate::ValidationError::new(::core::stringify!(#struct_name), kind),
+                        ),
+                    }
+                }
+            }
+        } else {
+            let constructor_vis = self.constructor_vis;
+            quote! {
+                /// Views `value` as this type without copying it.
+                #constructor_vis const fn new(value: &str) -> &Self {
+                    Self::__holda_from_str(value)
+                }
+            }
+        };
+
+        let display_impl = if self.skip_display {
+            quote! {}
+        } else {
+            quote! {
+                impl ::core::fmt::Display for #ref_name {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        ::core::fmt::Display::fmt(&self.inner, f)
+                    }
+                }
+            }
+        };
+
+        let debug_impl = if self.redact_debug {
+            let redacted = format!("{ref_name}(<redacted>)");
+            quote! {
+                impl ::core::fmt::Debug for #ref_name {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.write_str(#redacted)
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl ::core::fmt::Debug for #ref_name {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        ::core::fmt::Debug::fmt(&self.inner, f)
+                    }
+                }
+            }
+        };
+
+        let eq_impl = if self.skip_eq {
+            quote! {}
+        } else {
+            quote! {
+                impl ::core::cmp::PartialEq for #ref_name {
+                    fn eq(&self, other: &Self) -> bool {
+                        self.inner == other.inner
+                    }
+                }
+
+                impl ::core::cmp::Eq for #ref_name {}
+
+                impl ::core::cmp::PartialEq<str> for #ref_name {
+                    fn eq(&self, other: &str) -> bool {
+                        &self.inner == other
+                    }
+                }
+            }
+        };
+
+        let ord_impl = if self.skip_ord {
+            quote! {}
+        } else {
+            quote! {
+                impl ::core::cmp::PartialOrd for #ref_name {
+                    fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                        ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                    }
+                }
+
+                impl ::core::cmp::Ord for #ref_name {
+                    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                        ::core::cmp::Ord::cmp(&self.inner, &other.inner)
+                    }
+                }
+            }
+        };
+
+        let hash_impl = if self.skip_hash {
+            quote! {}
+        } else {
+            quote! {
+                impl ::core::hash::Hash for #ref_name {
+                    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        ::core::hash::Hash::hash(&self.inner, state)
+                    }
+                }
+            }
+        };
+
+        let serialize_impl = if self.skip_serde {
+            quote! {}
+        } else {
+            quote! {
+                impl #krate::__private::serde::Serialize for #ref_name {
+                    fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                    where
+                        S: #krate::__private::serde::Serializer,
+                    {
+                        #krate::__private::serde::Serialize::serialize(&self.inner, serializer)
+                    }
+                }
+            }
+        };
+
+        let from_impl = if self.skip_from {
+            quote! {}
+        } else {
+            quote! {
+                impl ::core::convert::From<&#ref_name> for #struct_name {
+                    fn from(value: &#ref_name) -> Self {
+                        #krate::__private::alloc::borrow::ToOwned::to_owned(value)
+                    }
+                }
+            }
+        };
+
+        quote! {
+            #[doc = #doc]
+            #[repr(transparent)]
+            #vis struct #ref_name {
+                inner: str,
+            }
+
+            impl #ref_name {
+                const fn __holda_from_str(value: &str) -> &Self {
+                    // SAFETY: `repr(transparent)` gives the struct the layout
+                    // of its only field, `str`, and the lifetime carries over.
+                    unsafe { &*(value as *const str as *const Self) }
+                }
+
+                #constructor
+
+                /// The borrowed string.
+                pub const fn as_str(&self) -> &str {
+                    &self.inner
+                }
+            }
+
+            impl ::core::ops::Deref for #ref_name {
+                type Target = str;
+
+                fn deref(&self) -> &str {
+                    &self.inner
+                }
+            }
+
+            impl ::core::convert::AsRef<str> for #ref_name {
+                fn as_ref(&self) -> &str {
+                    &self.inner
+                }
+            }
+
+            impl ::core::convert::AsRef<#ref_name> for #struct_name {
+                fn as_ref(&self) -> &#ref_name {
+                    #ref_name::__holda_from_str(::core::convert::AsRef::<str>::as_ref(&self.#inner_ident))
+                }
+            }
+
+            // `Eq`, `Ord` and `Hash` of both types delegate to the string, so
+            // lookups by `&NameRef` agree with the owned keys.
+            impl ::core::borrow::Borrow<#ref_name> for #struct_name {
+                fn borrow(&self) -> &#ref_name {
+                    #ref_name::__holda_from_str(::core::convert::AsRef::<str>::as_ref(&self.#inner_ident))
+                }
+            }
+
+            impl #krate::__private::alloc::borrow::ToOwned for #ref_name {
+                type Owned = #struct_name;
+
+                fn to_owned(&self) -> #struct_name {
+                    #struct_name {
+                        #inner_ident: <#inner_type as #krate::__private::StringInner>::from_borrowed(&self.inner),
+                    }
+                }
+            }
+
+            #from_impl
+            #display_impl
+            #debug_impl
+            #eq_impl
+            #ord_impl
+            #hash_impl
+            #serialize_impl
+        }
+    }
+}
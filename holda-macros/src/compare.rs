@@ -0,0 +1,201 @@
+//! `#[holda(CompareInner)]`: `PartialEq`/`PartialOrd` against the inner type
+//! and, for StringHolda, the borrowed and owned string types, in both
+//! directions.
+
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::GenericArgument;
+use syn::Path;
+use syn::PathArguments;
+use syn::Type;
+
+pub struct CompareInner<'a> {
+    pub krate: &'a Path,
+    pub struct_name: &'a Ident,
+    pub inner_ident: &'a Ident,
+    pub inner_type: &'a Type,
+    pub is_string: bool,
+    /// Case-insensitive `eq` and `cmp` functions, if any.
+    pub case_fns: Option<(&'a TokenStream, &'a TokenStream)>,
+    pub eq: bool,
+    pub ord: bool,
+}
+
+impl CompareInner<'_> {
+    pub fn impls(&self) -> TokenStream {
+        let krate = self.krate;
+        let mut impls = Vec::new();
+        if self.is_string {
+            let others = [
+                (quote! {}, quote! { str }),
+                (quote! { <'a> }, quote! { &'a str }),
+                (
+                    quote! {},
+                    quote! { #krate::__private::alloc::string::String },
+                ),
+                (
+                    quote! { <'a> },
+                    quote! { #krate::__private::alloc::borrow::Cow<'a, str> },
+                ),
+            ];
+            for (generics, other) in others {
+                impls.push(self.str_impls(&generics, &other));
+            }
+            if !is_string_family(self.inner_type) {
+                impls.push(self.inner_impls());
+            }
+        } else {
+            impls.push(self.inner_impls());
+        }
+        quote! { #(#impls)* }
+    }
+
+    fn inner_impls(&self) -> TokenStream {
+        let struct_name = self.struct_name;
+        let inner_ident = self.inner_ident;
+        let inner_type = self.inner_type;
+        let (eq_forward, eq_reverse, cmp_forward, cmp_reverse) = match self.case_fns {
+            Some((eq, cmp)) => {
+                let this = quote! { ::core::convert::AsRef::<str>::as_ref(&self.#inner_ident) };
+                let other_inner =
+                    quote! { ::core::convert::AsRef::<str>::as_ref(&other.#inner_ident) };
+                let this_str = quote! { ::core::convert::AsRef::<str>::as_ref(self) };
+                let other_str = quote! { ::core::convert::AsRef::<str>::as_ref(other) };
+                (
+                    quote! { #eq(#this, #other_str) },
+                    quote! { #eq(#this_str, #other_inner) },
+                    quote! { ::core::option::Option::Some(#cmp(#this, #other_str)) },
+                    quote! { ::core::option::Option::Some(#cmp(#this_str, #other_inner)) },
+                )
+            }
+            None => (
+                quote! { self.#inner_ident == *other },
+                quote! { *self == other.#inner_ident },
+                quote! { ::core::cmp::PartialOrd::partial_cmp(&self.#inner_ident, other) },
+                quote! { ::core::cmp::PartialOrd::partial_cmp(self, &other.#inner_ident) },
+            ),
+        };
+        let eq = if self.eq {
+            quote! {
+                impl ::core::cmp::PartialEq<#inner_type> for #struct_name
+                where #inner_type: ::core::cmp::PartialEq
+                {
+                    fn eq(&self, other: &#inner_type) -> bool {
+                        #eq_forward
+                    }
+                }
+
+                impl ::core::cmp::PartialEq<#struct_name> for #inner_type
+                where #inner_type: ::core::cmp::PartialEq
+                {
+                    fn eq(&self, other: &#struct_name) -> bool {
+                        #eq_reverse
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let ord = if self.ord {
+            quote! {
+                impl ::core::cmp::PartialOrd<#inner_type> for #struct_name
+                where #inner_type: ::core::cmp::PartialOrd
+                {
+                    fn partial_cmp(&self, other: &#inner_type) -> ::core::option::Option<::core::cmp::Ordering> {
+                        #cmp_forward
+                    }
+                }
+
+                impl ::core::cmp::PartialOrd<#struct_name> for #inner_type
+                where #inner_type: ::core::cmp::PartialOrd
+                {
+                    fn partial_cmp(&self, other: &#struct_name) -> ::core::option::Option<::core::cmp::Ordering> {
+                        #cmp_reverse
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        quote! { #eq #ord }
+    }
+
+    fn str_impls(&self, generics: &TokenStream, other: &TokenStream) -> TokenStream {
+        let struct_name = self.struct_name;
+        let inner_ident = self.inner_ident;
+        let this = quote! { ::core::convert::AsRef::<str>::as_ref(&self.#inner_ident) };
+        let other_inner = quote! { ::core::convert::AsRef::<str>::as_ref(&other.#inner_ident) };
+        let this_str = quote! { ::core::convert::AsRef::<str>::as_ref(self) };
+        let other_str = quote! { ::core::convert::AsRef::<str>::as_ref(other) };
+        let (eq_forward, eq_reverse, cmp_forward, cmp_reverse) = match self.case_fns {
+            Some((eq, cmp)) => (
+                quote! { #eq(#this, #other_str) },
+                quote! { #eq(#this_str, #other_inner) },
+                quote! { ::core::option::Option::Some(#cmp(#this, #other_str)) },
+                quote! { ::core::option::Option::Some(#cmp(#this_str, #other_inner)) },
+            ),
+            None => (
+                quote! { #this == #other_str },
+                quote! { #this_str == #other_inner },
+                quote! { ::core::cmp::PartialOrd::partial_cmp(#this, #other_str) },
+                quote! { ::core::cmp::PartialOrd::partial_cmp(#this_str, #other_inner) },
+            ),
+        };
+        let eq = if self.eq {
+            quote! {
+                impl #generics ::core::cmp::PartialEq<#other> for #struct_name {
+                    fn eq(&self, other: &#other) -> bool {
+                        #eq_forward
+                    }
+                }
+
+                impl #generics ::core::cmp::PartialEq<#struct_name> for #other {
+                    fn eq(&self, other: &#struct_name) -> bool {
+                        #eq_reverse
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let ord = if self.ord {
+            quote! {
+                impl #generics ::core::cmp::PartialOrd<#other> for #struct_name {
+                    fn partial_cmp(&self, other: &#other) -> ::core::option::Option<::core::cmp::Ordering> {
+                        #cmp_forward
+                    }
+                }
+
+                impl #generics ::core::cmp::PartialOrd<#struct_name> for #other {
+                    fn partial_cmp(&self, other: &#struct_name) -> ::core::option::Option<::core::cmp::Ordering> {
+                        #cmp_reverse
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        quote! { #eq #ord }
+    }
+}
+
+/// Whether `ty` is spelled `String` or `Cow<_, str>`, whose impls the string
+/// family already covers.
+fn is_string_family(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.path.segments.last().is_some_and(|segment| {
+        match &segment.arguments {
+            PathArguments::None => segment.ident == "String",
+            PathArguments::AngleBracketed(args) => {
+                segment.ident == "Cow"
+                    && args.args.iter().any(|arg| {
+                        matches!(arg, GenericArgument::Type(Type::Path(path)) if path.path.is_ident("str"))
+                    })
+            }
+            PathArguments::Parenthesized(_) => false,
+        }
+    })
+}
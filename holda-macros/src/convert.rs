@@ -0,0 +1,158 @@
+//! `from(Other)`, `try_from(Other)` and `into(Other)`: conversions between
+//! holda types through their inner values.
+
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::quote_spanned;
+use syn::Path;
+use syn::Token;
+use syn::Type;
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+
+enum Direction {
+    From,
+    TryFrom,
+    Into,
+}
+
+struct Conversion {
+    direction: Direction,
+    other: Type,
+    /// Maps the source inner value to the target inner type. Without it both
+    /// inner types must be the same.
+    with: Option<Path>,
+}
+
+#[derive(Default)]
+pub struct Conversions {
+    conversions: Vec<Conversion>,
+}
+
+impl Conversions {
+    /// Consumes `meta` if it is a conversion option, returning whether it did.
+    pub fn parse(&mut self, meta: &ParseNestedMeta) -> syn::Result<bool> {
+        let direction = if meta.path.is_ident("from") {
+            Direction::From
+        } else if meta.path.is_ident("try_from") {
+            Direction::TryFrom
+        } else if meta.path.is_ident("into") {
+            Direction::Into
+        } else {
+            return Ok(false);
+        };
+        let content;
+        syn::parenthesized!(content in meta.input);
+        let other = content.parse()?;
+        let with = if content.parse::<Option<Token![,]>>()?.is_some() && !content.is_empty() {
+            let key: Ident = content.parse()?;
+            if key != "with" {
+                return Err(syn::Error::new(key.span(), "expected `with = path::to_fn`"));
+            }
+            content.parse::<Token![=]>()?;
+            let with = content.parse()?;
+            content.parse::<Option<Token![,]>>()?;
+            Some(with)
+        } else {
+            None
+        };
+        if !content.is_empty() {
+            return Err(content.error("expected `with = path::to_fn`"));
+        }
+        self.conversions.push(Conversion {
+            direction,
+            other,
+            with,
+        });
+        Ok(true)
+    }
+
+    pub fn impls(
+        &self,
+        krate: &Path,
+        struct_name: &Ident,
+        inner_ident: &Ident,
+        inner_type: &Type,
+        validated: bool,
+    ) -> syn::Result<TokenStream> {
+        let mut impls = Vec::new();
+        for Conversion {
+            direction,
+            other,
+            with,
+        } in &self.conversions
+        {
+            let wrapper = quote! { #krate::__private::Wrapper };
+            let construct = quote! { #krate::__private::Construct };
+            let source = match direction {
+                Direction::Into => quote! { value.#inner_ident },
+                _ => quote! { #wrapper::into_inner(value) },
+            };
+            // Without a mapping function the inner types have to agree, which
+            // the bound checks where the conversion is declared.
+            let (inner, bound) = match (with, direction) {
+                (Some(with), Direction::Into) => (
+                    quote! { #with(#source) },
+                    quote! { #other: #construct<Output = #other> },
+                ),
+                (Some(with), _) => (quote! { #with(#source) }, quote! { #other: #wrapper }),
+                (None, Direction::Into) => (
+                    source,
+                    quote_spanned! {other.span()=>
+                        #other: #construct<Inner = #inner_type, Output = #other>
+                    },
+                ),
+                (None, _) => (
+                    source,
+                    quote_spanned! {other.span()=> #other: #wrapper<Inner = #inner_type> },
+                ),
+            };
+            let conversion = match direction {
+                Direction::From if validated => {
+                    return Err(syn::Error::new(
+                        other.span(),
+                        "validated types cannot convert infallibly; use `try_from`",
+                    ));
+                }
+                Direction::From => quote! {
+                    impl ::core::convert::From<#other> for #struct_name
+                    where #bound
+                    {
+                        fn from(value: #other) -> Self {
+                            Self::new(#inner)
+                        }
+                    }
+                },
+                Direction::TryFrom if !validated => {
+                    return Err(syn::Error::new(
+                        other.span(),
+                        "types without validators convert infallibly; use `from`",
+                    ));
+                }
+                Direction::TryFrom => quote! {
+                    impl ::core::convert::TryFrom<#other> for #struct_name
+                    where #bound
+                    {
+                        type Error = #krate::ValidationError;
+
+                        fn try_from(value: #other) -> ::core::result::Result<Self, Self::Error> {
+                            Self::try_new(#inner)
+                        }
+                    }
+                },
+                Direction::Into => quote! {
+                    impl ::core::convert::From<#struct_name> for #other
+                    where #bound
+                    {
+                        fn from(value: #struct_name) -> Self {
+                            <#other as #construct>::construct(#inner)
+                        }
+                    }
+                },
+            };
+            impls.push(conversion);
+        }
+        Ok(quote! { #(#impls)* })
+    }
+}
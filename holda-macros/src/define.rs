@@ -0,0 +1,104 @@
+//! `holda::define!`: many holda types in one block, expanded by the same code
+//! as the derives.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Attribute;
+use syn::Ident;
+use syn::Token;
+use syn::Type;
+use syn::Visibility;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
+use syn::parse_quote;
+
+use crate::sources::string_kind;
+
+/// `#[attrs] vis Name: Inner [options];`
+struct Definition {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    inner_type: Type,
+    options: TokenStream,
+}
+
+pub struct Definitions {
+    definitions: Vec<Definition>,
+}
+
+impl Parse for Definition {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let inner_type = input.parse()?;
+        let options = if input.peek(syn::token::Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+            content.parse()?
+        } else {
+            TokenStream::new()
+        };
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            inner_type,
+            options,
+        })
+    }
+}
+
+impl Parse for Definitions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut definitions = Vec::new();
+        while !input.is_empty() {
+            definitions.push(input.parse()?);
+            if !input.is_empty() {
+                input.parse::<Token![;]>()?;
+            }
+        }
+        Ok(Self { definitions })
+    }
+}
+
+impl Definitions {
+    pub fn expand(self) -> TokenStream {
+        let items = self.definitions.into_iter().map(|definition| {
+            let Definition {
+                attrs,
+                vis,
+                name,
+                inner_type,
+                options,
+            } = definition;
+            let is_string = string_kind(&inner_type).is_some();
+            // `#[holda(...)]` is only meaningful to the derive, so it is left
+            // off the emitted struct. The derive still sees the others, such
+            // as `#[derive(...)]` and `#[repr(...)]`.
+            let (holda_attrs, attrs): (Vec<_>, Vec<_>) = attrs
+                .into_iter()
+                .partition(|attr| attr.path().is_ident("holda"));
+            let ast = parse_quote! {
+                #(#holda_attrs)*
+                #(#attrs)*
+                #[holda(#options)]
+                #vis struct #name {
+                    inner: #inner_type,
+                }
+            };
+            let impls = TokenStream::from(crate::expand(ast, is_string));
+            quote! {
+                #(#attrs)*
+                #vis struct #name {
+                    inner: #inner_type,
+                }
+
+                #impls
+            }
+        });
+        quote! { #(#items)* }
+    }
+}
@@ -0,0 +1,82 @@
+//! `#[holda(flatten)]`: delegation through an inner holda type to every layer
+//! below it, built on the `Layer` support trait.
+
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+use syn::Type;
+
+pub struct Flatten<'a> {
+    pub krate: &'a Path,
+    pub struct_name: &'a Ident,
+    pub inner_ident: &'a Ident,
+    pub inner_type: &'a Type,
+    pub validated: bool,
+}
+
+impl Flatten<'_> {
+    /// `Layer<T>` for every `T` the inner holda type is a layer of.
+    pub fn layer_impl(&self) -> TokenStream {
+        let krate = self.krate;
+        let struct_name = self.struct_name;
+        let inner_ident = self.inner_ident;
+        let inner_type = self.inner_type;
+        quote! {
+            impl<T: ?::core::marker::Sized> #krate::__private::Layer<T> for #struct_name
+            where #inner_type: #krate::__private::Layer<T>
+            {
+                fn layer(&self) -> &T {
+                    #krate::__private::Layer::<T>::layer(&self.#inner_ident)
+                }
+            }
+        }
+    }
+
+    /// `AsRef` to every layer, replacing `AsRef<Inner>`.
+    pub fn as_ref_impl(&self) -> TokenStream {
+        let krate = self.krate;
+        let struct_name = self.struct_name;
+        let inner_ident = self.inner_ident;
+        let inner_type = self.inner_type;
+        quote! {
+            impl<T: ?::core::marker::Sized> ::core::convert::AsRef<T> for #struct_name
+            where #inner_type: #krate::__private::Layer<T>
+            {
+                fn as_ref(&self) -> &T {
+                    #krate::__private::Layer::<T>::layer(&self.#inner_ident)
+                }
+            }
+        }
+    }
+
+    /// Conversions from anything the inner type converts from, replacing
+    /// `From<Inner>`. A blanket `TryFrom` would overlap the one in `core`, so
+    /// validated types get a generic `try_new` instead.
+    pub fn conversion_impl(&self) -> TokenStream {
+        let krate = self.krate;
+        let struct_name = self.struct_name;
+        let inner_type = self.inner_type;
+        if self.validated {
+            quote! {
+                impl ::core::convert::TryFrom<#inner_type> for #struct_name {
+                    type Error = #krate::ValidationError;
+
+                    fn try_from(value: #inner_type) -> ::core::result::Result<Self, Self::Error> {
+                        Self::try_new(value)
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl<T> ::core::convert::From<T> for #struct_name
+                where #inner_type: ::core::convert::From<T>
+                {
+                    fn from(value: T) -> Self {
+                        Self::new(<#inner_type as ::core::convert::From<T>>::from(value))
+                    }
+                }
+            }
+        }
+    }
+}
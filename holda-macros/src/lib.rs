@@ -0,0 +1,1446 @@
+use borrowed::Borrowed;
+use compare::CompareInner;
+use convert::Conversions;
+use define::Definitions;
+use flatten::Flatten;
+use normalize::Normalizers;
+use preset::Allowlist;
+use proc_macro::TokenStream;
+use quote::quote;
+use ref_cast::RefCast;
+use sources::Sources;
+use sources::StringKind;
+use syn::DataStruct;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::Ident;
+use syn::LitStr;
+use syn::Path;
+use syn::Type;
+use syn::Visibility;
+use syn::parse_macro_input;
+use syn::parse_quote;
+use transform::Transform;
+use validate::Validators;
+
+mod borrow;
+mod borrowed;
+mod compare;
+mod convert;
+mod define;
+mod flatten;
+mod newtype;
+mod normalize;
+mod preset;
+mod ref_cast;
+mod regex;
+mod sources;
+mod transform;
+mod validate;
+
+#[proc_macro_derive(StringHolda, attributes(holda))]
+pub fn string_holda_derive(input: TokenStream) -> TokenStream {
+    string_holder_derive_impl(input, true)
+}
+
+#[proc_macro_derive(Holda, attributes(holda))]
+pub fn holda_derive(input: TokenStream) -> TokenStream {
+    string_holder_derive_impl(input, false)
+}
+
+/// Defines several holda types at once, as `Vis Name: Inner [options];`
+/// entries. String inners get `StringHolda`, anything else `Holda`, and the
+/// options are those of `#[holda(...)]`.
+#[proc_macro]
+pub fn define(input: TokenStream) -> TokenStream {
+    let definitions = parse_macro_input!(input as Definitions);
+    definitions.expand().into()
+}
+
+/// Rewrites a single-field struct into a holda newtype: the inner field is
+/// made private, `#[repr(transparent)]` and `#[must_use]` are added, and the
+/// impls of `StringHolda` or `Holda` follow. Takes the options of
+/// `#[holda(...)]`, plus `Copy`.
+#[proc_macro_attribute]
+pub fn newtype(args: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as DeriveInput);
+    newtype::newtype(args.into(), item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn string_holder_derive_impl(input: TokenStream, is_string: bool) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    expand(ast, is_string)
+}
+
+/// The derive shared by `StringHolda`, `Holda` and `define!`.
+fn expand(ast: DeriveInput, is_string: bool) -> TokenStream {
+    // Get the struct name
+    let struct_name = &ast.ident;
+
+    // Parse the attributes to determine which traits to skip
+    let mut skip_display = false;
+    let mut skip_eq = false;
+    let mut skip_ord = false;
+    let mut skip_hash = false;
+    let mut skip_clone = false;
+    let mut skip_serde = false;
+    let mut case_insensitive = None;
+    let mut compare_inner = false;
+    let mut borrow = None;
+    let mut borrowed: Option<(Path, Ident)> = None;
+    let mut skip_deref = false;
+    let mut skip_deref_mut = false;
+    let mut readonly = false;
+    let mut deref_target: Option<Type> = None;
+    let mut constructor_vis: Visibility = parse_quote!(pub);
+    let mut skip_from = false;
+    let mut skip_into = false;
+    let mut auto = false;
+    let mut krate: Path = parse_quote!(::holda);
+    let mut allowlist: Option<Allowlist> = None;
+    let mut new_unchecked = false;
+    let mut ref_cast = None;
+    let mut interned = None;
+    let mut as_mut = None;
+    let mut flatten = false;
+    let mut from_str = None;
+    let mut from_str_error = None;
+    let mut normalizers = Normalizers::default();
+    let mut validators = Validators::default();
+    let mut conversions = Conversions::default();
+
+    for attr in &ast.attrs {
+        if attr.path().is_ident("holda") {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("NoDisplay") {
+                    skip_display = true;
+                } else if meta.path.is_ident("NoEq") {
+                    skip_eq = true;
+                } else if meta.path.is_ident("NoOrd") {
+                    skip_ord = true;
+                } else if meta.path.is_ident("NoHash") {
+                    skip_hash = true;
+                } else if meta.path.is_ident("NoClone") {
+                    skip_clone = true;
+                } else if meta.path.is_ident("NoSerde") {
+                    skip_serde = true;
+                } else if meta.path.is_ident("CompareInner") {
+                    compare_inner = true;
+                } else if meta.path.is_ident("NoDeref") {
+                    skip_deref = true;
+                } else if meta.path.is_ident("NoDerefMut") {
+                    skip_deref_mut = true;
+                } else if meta.path.is_ident("NoFrom") {
+                    skip_from = true;
+                } else if meta.path.is_ident("NoInto") {
+                    skip_into = true;
+                } else if meta.path.is_ident("new_unchecked") {
+                    new_unchecked = true;
+                } else if meta.path.is_ident("interned") {
+                    if !is_string {
+                        return Err(meta.error("`interned` is only supported by StringHolda"));
+                    }
+                    interned = Some(meta.path.clone());
+                } else if meta.path.is_ident("ref_cast") {
+                    ref_cast = Some(meta.path.clone());
+                } else if meta.path.is_ident("constructor") {
+                    let vis: LitStr = meta.value()?.parse()?;
+                    constructor_vis = if vis.value() == "private" {
+                        Visibility::Inherited
+                    } else {
+                        vis.parse()?
+                    };
+                } else if meta.path.is_ident("deref") {
+                    deref_target = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("crate") {
+                    krate = meta.value()?.parse::<LitStr>()?.parse()?;
+                } else if meta.path.is_ident("auto") {
+                    auto = true;
+                } else if meta.path.is_ident("readonly") {
+                    readonly = true;
+                } else if meta.path.is_ident("FromStr") {
+                    if is_string {
+                        return Err(meta.error("StringHolda always implements `FromStr`"));
+                    }
+                    from_str = Some(meta.path.clone());
+                } else if meta.path.is_ident("flatten") {
+                    if is_string {
+                        return Err(meta.error("`flatten` is only supported by Holda"));
+                    }
+                    flatten = true;
+                } else if meta.path.is_ident("AsMut") {
+                    as_mut = Some(meta.path.clone());
+                } else if meta.path.is_ident("Borrow") {
+                    borrow = Some(meta.path.clone());
+                } else if meta.path.is_ident("borrowed") {
+                    if !is_string {
+                        return Err(meta.error("`borrowed` is only supported by StringHolda"));
+                    }
+                    borrowed = Some((meta.path.clone(), meta.value()?.parse()?));
+                } else if meta.path.is_ident("from_str_error") {
+                    if !is_string {
+                        return Err(meta.error("`from_str_error` is only supported by StringHolda"));
+                    }
+                    let error: LitStr = meta.value()?.parse()?;
+                    from_str_error = Some(match error.value().as_str() {
+                        "eyre" if cfg!(feature = "std") => FromStrError::Eyre,
+                        "eyre" => {
+                            return Err(syn::Error::new(
+                                error.span(),
+                                "`eyre` errors need the `std` feature",
+                            ));
+                        }
+                        "validation" => FromStrError::Validation,
+                        _ => {
+                            return Err(syn::Error::new(
+                                error.span(),
+                                "expected \"eyre\" or \"validation\"",
+                            ));
+                        }
+                    });
+                } else if meta.path.is_ident("case_insensitive") {
+                    if !is_string {
+                        return Err(
+                            meta.error("`case_insensitive` is only supported by StringHolda")
+                        );
+                    }
+                    let mode: LitStr = meta.value()?.parse()?;
+                    case_insensitive = Some(match mode.value().as_str() {
+                        "ascii" => CaseMode::Ascii,
+                        "unicode" => CaseMode::Unicode,
+                        _ => {
+                            return Err(syn::Error::new(
+                                mode.span(),
+                                "expected \"ascii\" or \"unicode\"",
+                            ));
+                        }
+                    });
+                } else if let Some(parsed) = Allowlist::parse(&meta)? {
+                    if allowlist.is_some() {
+                        return Err(meta.error("only one of `only` and `preset` may be given"));
+                    }
+                    allowlist = Some(parsed);
+                } else if !normalizers.parse(&meta, is_string)?
+                    && !conversions.parse(&meta)?
+                    && !validators.parse(&meta, is_string)?
+                {
+                    return Err(meta.error("unknown holda option"));
+                }
+                Ok(())
+            });
+            if let Err(err) = parsed {
+                return err.to_compile_error().into();
+            }
+        }
+    }
+
+    if let (Some(path), Some(_)) = (&borrow, case_insensitive) {
+        return syn::Error::new_spanned(
+            path,
+            "`Borrow` would disagree with the case-insensitive `Eq` and `Hash`; \
+             enable the `equivalent` feature of holda for borrowed lookups instead",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if let (Some((path, _)), Some(_)) = (&borrowed, case_insensitive) {
+        return syn::Error::new_spanned(
+            path,
+            "`borrowed` would disagree with the case-insensitive `Eq` and `Hash`",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if let (Some((path, _)), Some(_)) = (&borrowed, &deref_target) {
+        return syn::Error::new_spanned(path, "`borrowed` and `deref` both set the `Deref` target")
+            .to_compile_error()
+            .into();
+    }
+
+    // `From`, `FromStr`, `Deserialize` and `holda_lit!` are public ways to
+    // construct a value, so a restricted constructor leaves them out.
+    let public_constructor = matches!(constructor_vis, Visibility::Public(_));
+    skip_from |= !public_constructor;
+    if let (false, Some(path)) = (public_constructor, &from_str) {
+        return syn::Error::new_spanned(
+            path,
+            "`FromStr` would construct values outside the constructor's visibility",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if let (true, Some(target)) = (skip_deref, &deref_target) {
+        return syn::Error::new_spanned(target, "`deref` has no effect together with `NoDeref`")
+            .to_compile_error()
+            .into();
+    }
+
+    #[cfg(not(feature = "serde"))]
+    {
+        skip_serde = true;
+    }
+
+    let mut redact_debug = false;
+    let mut skip_as_ref = false;
+    let mut skip_from_str = false;
+    let mut skip_transform = false;
+    let mut skip_mutate = false;
+    if let Some(allowlist) = &allowlist {
+        skip_display |= !allowlist.allows("Display");
+        skip_eq |= !allowlist.allows("Eq");
+        skip_ord |= !allowlist.allows("Ord");
+        skip_hash |= !allowlist.allows("Hash");
+        skip_clone |= !allowlist.allows("Clone");
+        skip_serde |= !allowlist.allows("Serde");
+        skip_deref |= !allowlist.allows("Deref");
+        skip_deref_mut |= !allowlist.allows("DerefMut");
+        skip_from |= !allowlist.allows("From");
+        skip_into |= !allowlist.allows("Into");
+        skip_as_ref = !allowlist.allows("AsRef");
+        skip_from_str = !allowlist.allows("FromStr");
+        skip_transform = !allowlist.allows("Transform");
+        skip_mutate = !allowlist.allows("Mutate");
+        redact_debug = allowlist.redact_debug;
+    }
+
+    // Get the field name (assuming it's a single field named "inner")
+    let fields = if let syn::Data::Struct(DataStruct {
+        fields: Fields::Named(named),
+        ..
+    }) = ast.data
+    {
+        named
+    } else {
+        panic!("Only structs with named fields are supported");
+    };
+
+    // Get the type of the "inner" field
+    let inner_field = fields
+        .named
+        .first()
+        .expect("Struct must have at least one field")
+        .clone();
+    let inner_ident = inner_field.ident.expect("Field must be named");
+    let inner_type = inner_field.ty;
+
+    // `Symbol` compares and hashes its handle, which a borrowed `str` cannot
+    // agree with.
+    let symbol = is_string && sources::is_symbol(&inner_type);
+    if let (false, Some(path)) = (symbol, &interned) {
+        return syn::Error::new_spanned(path, "`interned` needs a `holda::Symbol` inner")
+            .to_compile_error()
+            .into();
+    }
+    if let (true, Some(path)) = (
+        symbol,
+        borrow.as_ref().or(borrowed.as_ref().map(|(path, _)| path)),
+    ) {
+        return syn::Error::new_spanned(
+            path,
+            "`Symbol` hashes its handle, which would disagree with a borrowed `str`",
+        )
+        .to_compile_error()
+        .into();
+    }
+    // Interned strings are never freed, so a `Symbol` is only made from a
+    // value that passed normalization and validation. Until then it is a
+    // `String`.
+    let source_type: Type = if symbol {
+        parse_quote!(#krate::__private::alloc::string::String)
+    } else {
+        inner_type.clone()
+    };
+    let store = if symbol {
+        quote! { <#inner_type as #krate::__private::StringInner>::from_owned(value) }
+    } else {
+        quote! { value }
+    };
+    if interned.is_some() && deref_target.is_none() {
+        deref_target = Some(parse_quote!(str));
+    }
+
+    let validated = !validators.is_empty();
+    let normalized = !normalizers.is_empty();
+    let literal_capable = is_string || is_primitive(&inner_type);
+    let bounded = is_string && sources::string_kind(&inner_type) == Some(StringKind::Bounded);
+
+    // A derived `Default` builds the inner type's default without going
+    // through the constructor.
+    if let (true, Some(default)) = (validated || normalized, derived_default(&ast.attrs)) {
+        return syn::Error::new_spanned(
+            default,
+            "`Default` would bypass the constructor's validation and normalization",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if bounded && normalized {
+        return syn::Error::new_spanned(
+            &inner_type,
+            "normalization is not supported for fixed-capacity inner types",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // Fixed-capacity inners are built from a `&str`, which fails if it does
+    // not fit.
+    let bounded_from_str = |value: proc_macro2::TokenStream| {
+        quote! {
+            <#inner_type as #krate::__private::BoundedString>::try_from_str(::core::stringify!(#struct_name), #value)
+        }
+    };
+
+    let (normalize_impl, normalize) = if normalized {
+        let steps = normalizers.steps(&krate);
+        (
+            quote! {
+                impl #struct_name {
+                    fn __holda_normalize(value: #source_type) -> #source_type {
+                        #steps
+                        value
+                    }
+                }
+            },
+            quote! { let value = Self::__holda_normalize(value); },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let validation_impl = if validated {
+        let checks = validators.checks(&krate);
+        let check_value = if is_string {
+            quote! { &str }
+        } else {
+            quote! { &#inner_type }
+        };
+        let constness = if literal_capable {
+            quote! { const }
+        } else {
+            quote! {}
+        };
+        quote! {
+            impl #struct_name {
+                #constness fn __holda_check(value: #check_value) -> ::core::result::Result<(), #krate::ValidationErrorKind> {
+                    #checks
+                    ::core::result::Result::Ok(())
+                }
+
+                // Takes the inner type as declared, whatever clippy thinks of it.
+                #[allow(clippy::ptr_arg)]
+                fn __holda_validate(value: #check_value) -> ::core::result::Result<(), #krate::ValidationError> {
+                    Self::__holda_check(value)
+                        .map_err(|kind| #krate::ValidationError::new(::core::stringify!(#struct_name), kind))
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let literal_impl = if literal_capable && public_constructor {
+        let normalized_literal = if is_string {
+            normalizers.literal_checks(&krate)
+        } else {
+            quote! {}
+        };
+        // The validators only see the literal as written, which is the stored
+        // value unless a step has to run at runtime.
+        let check_capacity = if bounded {
+            quote! {
+                if value.len() > <#inner_type as #krate::__private::BoundedString>::CAPACITY {
+                    ::core::panic!("literal does not fit in the inner type's capacity");
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let check_literal = if validated && !normalizers.has_runtime_steps() {
+            let value = if is_string {
+                quote! { value }
+            } else {
+                quote! { &value }
+            };
+            quote! {
+                #normalized_literal
+                #check_capacity
+                if let ::core::result::Result::Err(kind) = Self::__holda_check(#value) {
+                    ::core::panic!("{}", kind.description());
+                }
+            }
+        } else if validated {
+            // A step that only runs at runtime could turn a valid literal
+            // into an invalid one, which would then only fail at runtime.
+            quote! {
+                let _ = value;
+                ::core::panic!("holda_lit! cannot validate literals of a type normalized by `nfc` or a custom function");
+            }
+        } else if normalized {
+            quote! {
+                #normalized_literal
+                let _ = value;
+            }
+        } else if bounded {
+            check_capacity
+        } else {
+            quote! {
+                let _ = value;
+            }
+        };
+        let literal_type = if is_string {
+            quote! { &'static str }
+        } else {
+            quote! { #inner_type }
+        };
+        // Steps that cannot run in const are applied here. Validated types
+        // with such steps never get this far, since the check above fails.
+        let from_literal = if normalized && validated {
+            quote! {
+                pub fn __holda_from_literal(value: #literal_type) -> Self {
+                    Self::try_new(value).expect("holda_lit! literal is invalid once normalized")
+                }
+            }
+        } else if normalized {
+            quote! {
+                pub fn __holda_from_literal(value: #literal_type) -> Self {
+                    Self::new(value)
+                }
+            }
+        } else if bounded {
+            let inner = bounded_from_str(quote! { value });
+            quote! {
+                pub fn __holda_from_literal(value: &'static str) -> Self {
+                    Self { #inner_ident: #inner.expect("holda_lit! literal does not fit") }
+                }
+            }
+        } else if is_string {
+            quote! {
+                pub fn __holda_from_literal(value: &'static str) -> Self {
+                    Self { #inner_ident: ::core::convert::Into::into(value) }
+                }
+            }
+        } else {
+            quote! {
+                pub const fn __holda_from_literal(value: #inner_type) -> Self {
+                    Self { #inner_ident: value }
+                }
+            }
+        };
+        quote! {
+            // Support for `holda::holda_lit!`
+            impl #struct_name {
+                #[doc(hidden)]
+                pub const fn __holda_check_literal(value: #literal_type) {
+                    #check_literal
+                }
+
+                #[doc(hidden)]
+                #from_literal
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // `eyre` needs `std`, so without it `FromStr` is left out unless an error
+    // type is chosen. Enabling `std` elsewhere in the build then only adds
+    // the impl, and never changes its error type.
+    let from_str_error = from_str_error.or(cfg!(feature = "std").then_some(FromStrError::Eyre));
+    let from_str_impl = if let (true, true, false, Some(error)) =
+        (is_string, public_constructor, skip_from_str, from_str_error)
+    {
+        let value = if bounded {
+            let value = bounded_from_str(quote! { s });
+            quote! { #value? }
+        } else {
+            quote! { #krate::__private::alloc::string::ToString::to_string(s) }
+        };
+        let (err, body) = match (error, validated) {
+            (FromStrError::Eyre, true) => (
+                quote! { #krate::__private::eyre::Error },
+                quote! { ::core::result::Result::Ok(Self::try_new(#value)?) },
+            ),
+            (FromStrError::Eyre, false) => (
+                quote! { #krate::__private::eyre::Error },
+                quote! { ::core::result::Result::Ok(Self::new(#value)) },
+            ),
+            (FromStrError::Validation, true) => (
+                quote! { #krate::ValidationError },
+                quote! { Self::try_new(#value) },
+            ),
+            (FromStrError::Validation, false) => (
+                quote! { #krate::ValidationError },
+                quote! { ::core::result::Result::Ok(Self::new(#value)) },
+            ),
+        };
+        quote! {
+            impl ::core::str::FromStr for #struct_name {
+                type Err = #err;
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    #body
+                }
+            }
+        }
+    } else if from_str.is_some() {
+        let body = if validated {
+            quote! { ::core::result::Result::Ok(Self::try_new(value)?) }
+        } else {
+            quote! { ::core::result::Result::Ok(Self::new(value)) }
+        };
+        quote! {
+            impl ::core::str::FromStr for #struct_name {
+                type Err = #krate::ParseError<<#inner_type as ::core::str::FromStr>::Err>;
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    let value = s.parse::<#inner_type>().map_err(|err| {
+                        #krate::ParseError::new(::core::stringify!(#struct_name), #krate::ParseErrorKind::Inner(err))
+                    })?;
+                    #body
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let constructor_impl = if validated && flatten {
+        // Accepts whatever the inner holda type converts from, fallibly or not.
+        quote! {
+            impl #struct_name {
+                #constructor_vis fn try_new<T>(value: T) -> ::core::result::Result<Self, #krate::ValidationError>
+                where
+                    #inner_type: ::core::convert::TryFrom<T>,
+                    #krate::ValidationError: ::core::convert::From<<#inner_type as ::core::convert::TryFrom<T>>::Error>,
+                {
+                    let value = <#inner_type as ::core::convert::TryFrom<T>>::try_from(value)?;
+                    #normalize
+                    Self::__holda_validate(&value)?;
+                    ::core::result::Result::Ok(Self { #inner_ident: value })
+                }
+            }
+        }
+    } else if validated {
+        quote! {
+            impl #struct_name {
+                #constructor_vis fn try_new(value: impl ::core::convert::Into<#source_type>) -> ::core::result::Result<Self, #krate::ValidationError> {
+                    let value: #source_type = ::core::convert::Into::into(value);
+                    #normalize
+                    Self::__holda_validate(&value)?;
+                    ::core::result::Result::Ok(Self { #inner_ident: #store })
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #struct_name {
+                #constructor_vis fn new(value: impl ::core::convert::Into<#source_type>) -> Self {
+                    let value: #source_type = ::core::convert::Into::into(value);
+                    #normalize
+                    Self { #inner_ident: #store }
+                }
+            }
+        }
+    };
+
+    let conversions_impl =
+        match conversions.impls(&krate, struct_name, &inner_ident, &inner_type, validated) {
+            Ok(impls) => impls,
+            Err(err) => return err.to_compile_error().into(),
+        };
+
+    let transform_impl = Transform {
+        krate: &krate,
+        struct_name,
+        inner_ident: &inner_ident,
+        inner_type: &inner_type,
+        source_type: &source_type,
+        constructor_vis: &constructor_vis,
+        validated,
+        readonly: readonly || skip_mutate,
+        skip_map: skip_transform,
+    }
+    .impls();
+
+    let new_unchecked_impl = if new_unchecked {
+        quote! {
+            impl #struct_name {
+                /// Wraps `value` without normalizing or validating it.
+                ///
+                /// # Safety
+                ///
+                /// `value` must already be normalized and accepted by the
+                /// validators, which the rest of the API relies on.
+                #constructor_vis const unsafe fn new_unchecked(value: #inner_type) -> Self {
+                    Self { #inner_ident: value }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // A cast skips the constructor, so there is no place to normalize.
+    let ref_cast_impl = match &ref_cast {
+        Some(path) if normalized => {
+            return syn::Error::new_spanned(path, "`ref_cast` would bypass normalization")
+                .to_compile_error()
+                .into();
+        }
+        Some(path) if !ref_cast::is_transparent(&ast.attrs) => {
+            return syn::Error::new_spanned(
+                path,
+                "`ref_cast` needs `#[repr(transparent)]` on the struct",
+            )
+            .to_compile_error()
+            .into();
+        }
+        Some(_) => RefCast {
+            krate: &krate,
+            struct_name,
+            inner_type: &inner_type,
+            constructor_vis: &constructor_vis,
+            validated,
+        }
+        .impls(),
+        None => quote! {},
+    };
+
+    let flattened = Flatten {
+        krate: &krate,
+        struct_name,
+        inner_ident: &inner_ident,
+        inner_type: &inner_type,
+        validated,
+    };
+
+    let from_impl = if skip_from {
+        quote! {}
+    } else if flatten {
+        flattened.conversion_impl()
+    } else if validated {
+        quote! {
+            impl ::core::convert::TryFrom<#inner_type> for #struct_name {
+                type Error = #krate::ValidationError;
+
+                fn try_from(value: #inner_type) -> ::core::result::Result<Self, Self::Error> {
+                    Self::try_new(value)
+                }
+            }
+        }
+    } else {
+        quote! {
+            // Implement From trait for other types
+            impl ::core::convert::From<#inner_type> for #struct_name {
+                fn from(value: #inner_type) -> Self {
+                    Self::new(value)
+                }
+            }
+        }
+    };
+
+    // `From<Self> for Inner` would make the blanket `From` of unvalidated
+    // flattened types overlap `From<Self> for Self`.
+    let into_impl = if skip_into || (flatten && !validated) {
+        quote! {}
+    } else {
+        quote! {
+            impl ::core::convert::From<#struct_name> for #inner_type {
+                fn from(value: #struct_name) -> Self {
+                    value.#inner_ident
+                }
+            }
+        }
+    };
+
+    // Mutable access to the inner value would skip normalization and
+    // validation.
+    let mutable = !readonly && !validated && !normalized;
+    if let (false, Some(path)) = (mutable, &as_mut) {
+        return syn::Error::new_spanned(
+            path,
+            "`AsMut` would bypass readonly mode, validation or normalization",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let string_sources = Sources {
+        krate: &krate,
+        struct_name,
+        inner_ident: &inner_ident,
+        inner_type: &inner_type,
+        validated,
+    };
+    // `ToOwned` builds the inner value from the borrowed `str`.
+    let borrowed = match &borrowed {
+        Some((path, _)) if !string_sources.is_string_inner() => {
+            return syn::Error::new_spanned(
+                path,
+                "`borrowed` needs a `String`, `Box<str>`, `Rc<str>`, `Arc<str>` or `Cow<'static, str>` inner",
+            )
+            .to_compile_error()
+            .into();
+        }
+        Some((_, ref_name)) => Some(Borrowed {
+            krate: &krate,
+            struct_name,
+            ref_name,
+            vis: &ast.vis,
+            inner_ident: &inner_ident,
+            inner_type: &inner_type,
+            constructor_vis: &constructor_vis,
+            validated,
+            normalized,
+            skip_display,
+            skip_eq,
+            skip_ord,
+            skip_hash,
+            skip_serde,
+            skip_from,
+            redact_debug,
+        }),
+        None => None,
+    };
+    let borrowed_impl = borrowed.as_ref().map(Borrowed::impls);
+
+    let string_sources_impl = if !is_string || skip_from {
+        quote! {}
+    } else if string_sources.is_string_inner() {
+        string_sources.conversions()
+    } else if bounded {
+        let value = bounded_from_str(quote! { value });
+        let body = if validated {
+            quote! { Self::try_new(#value?) }
+        } else {
+            quote! { ::core::result::Result::Ok(Self::new(#value?)) }
+        };
+        quote! {
+            impl ::core::convert::TryFrom<&str> for #struct_name {
+                type Error = #krate::ValidationError;
+
+                fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                    #body
+                }
+            }
+        }
+    } else if validated {
+        quote! {
+            //Implement TryFrom<&str>
+            impl ::core::convert::TryFrom<&str> for #struct_name {
+                type Error = #krate::ValidationError;
+
+                fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                    Self::try_new(value)
+                }
+            }
+        }
+    } else {
+        quote! {
+            //Implement From<&str>
+            impl ::core::convert::From<&str> for #struct_name {
+                fn from(value: &str) -> Self {
+                    Self::new(value)
+                }
+            }
+        }
+    };
+    let appends_impl = match (is_string, mutable && !skip_mutate) {
+        (true, true) => string_sources.appends(),
+        _ => quote! {},
+    };
+
+    let inner_mut = if mutable && !skip_mutate {
+        quote! {
+            pub fn inner_mut(&mut self) -> &mut #inner_type {
+                &mut self.#inner_ident
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let accessors_impl = quote! {
+        impl #struct_name {
+            pub fn into_inner(self) -> #inner_type {
+                self.#inner_ident
+            }
+
+            pub fn inner(&self) -> &#inner_type {
+                &self.#inner_ident
+            }
+
+            #inner_mut
+        }
+
+        impl #krate::__private::Wrapper for #struct_name {
+            type Inner = #inner_type;
+
+            fn into_inner(self) -> #inner_type {
+                self.#inner_ident
+            }
+        }
+    };
+
+    let as_mut_impl = if as_mut.is_some() {
+        quote! {
+            impl ::core::convert::AsMut<#inner_type> for #struct_name {
+                fn as_mut(&mut self) -> &mut #inner_type {
+                    &mut self.#inner_ident
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // In `auto` mode the higher-ranked binder keeps the bound from being
+    // checked eagerly, so an impl whose bound fails simply never applies.
+    let bound = |trait_path: proc_macro2::TokenStream| {
+        if auto {
+            quote! { for<'__holda> #inner_type: #trait_path }
+        } else {
+            quote! { #inner_type: #trait_path }
+        }
+    };
+    let display_bound = bound(quote! { ::core::fmt::Display });
+    let debug_bound = bound(quote! { ::core::fmt::Debug });
+    let partial_eq_bound = bound(quote! { ::core::cmp::PartialEq });
+    let eq_bound = bound(quote! { ::core::cmp::Eq });
+    let partial_ord_bound = bound(quote! { ::core::cmp::PartialOrd });
+    let ord_bound = bound(quote! { ::core::cmp::Ord });
+    let hash_bound = bound(quote! { ::core::hash::Hash });
+    let clone_bound = bound(quote! { ::core::clone::Clone });
+    let serialize_bound = bound(quote! { #krate::__private::serde::Serialize });
+    let deserialize_bound = bound(quote! { #krate::__private::serde::Deserialize<'de> });
+
+    // Mutation re-runs the constructor, on a copy when it can fail or rewrite
+    // the value.
+    let modify_impl = if readonly || skip_mutate {
+        quote! {}
+    } else if validated && !skip_clone {
+        quote! {
+            impl #struct_name {
+                pub fn try_modify<R>(&mut self, f: impl ::core::ops::FnOnce(&mut #inner_type) -> R) -> ::core::result::Result<R, #krate::ValidationError>
+                where #clone_bound
+                {
+                    let mut value = ::core::clone::Clone::clone(&self.#inner_ident);
+                    let result = f(&mut value);
+                    *self = Self::try_new(value)?;
+                    ::core::result::Result::Ok(result)
+                }
+            }
+        }
+    } else if normalized && !validated && !skip_clone {
+        quote! {
+            impl #struct_name {
+                pub fn modify<R>(&mut self, f: impl ::core::ops::FnOnce(&mut #inner_type) -> R) -> R
+                where #clone_bound
+                {
+                    let mut value = ::core::clone::Clone::clone(&self.#inner_ident);
+                    let result = f(&mut value);
+                    *self = Self::new(value);
+                    result
+                }
+            }
+        }
+    } else if !normalized && !validated {
+        quote! {
+            impl #struct_name {
+                pub fn modify<R>(&mut self, f: impl ::core::ops::FnOnce(&mut #inner_type) -> R) -> R {
+                    f(&mut self.#inner_ident)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let deref_impl = if let (false, Some(borrowed)) = (skip_deref, &borrowed) {
+        borrowed.deref_impl()
+    } else if !skip_deref {
+        let target = deref_target.as_ref().unwrap_or(&inner_type);
+        let as_ref_target = match &deref_target {
+            Some(target) if !flatten && !skip_as_ref => quote! {
+                impl ::core::convert::AsRef<#target> for #struct_name {
+                    fn as_ref(&self) -> &#target {
+                        &self.#inner_ident
+                    }
+                }
+            },
+            _ => quote! {},
+        };
+        quote! {
+            // Deref implementation
+            impl ::core::ops::Deref for #struct_name {
+                type Target = #target;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.#inner_ident
+                }
+            }
+
+            #as_ref_target
+        }
+    } else {
+        quote! {}
+    };
+
+    let as_ref_impl = if skip_as_ref {
+        quote! {}
+    } else if flatten {
+        flattened.as_ref_impl()
+    } else {
+        quote! {
+            impl ::core::convert::AsRef<#inner_type> for #struct_name {
+                fn as_ref(&self) -> &#inner_type {
+                    &self.#inner_ident
+                }
+            }
+        }
+    };
+
+    // The borrowed form a `StringHolda` or `deref` target adds as a layer.
+    let borrowed_layer = match &deref_target {
+        Some(target) => Some((quote! { #target }, quote! { &self.#inner_ident })),
+        None if is_string => Some((
+            quote! { str },
+            quote! { ::core::convert::AsRef::<str>::as_ref(&self.#inner_ident) },
+        )),
+        None => None,
+    };
+    // Layers are what a `flatten` type wrapping this one exposes as `AsRef`.
+    let layer_impl = if skip_as_ref {
+        quote! {}
+    } else if flatten {
+        flattened.layer_impl()
+    } else {
+        let borrowed_layer = borrowed_layer.map(|(target, borrow)| {
+            quote! {
+                impl #krate::__private::Layer<#target> for #struct_name {
+                    fn layer(&self) -> &#target {
+                        #borrow
+                    }
+                }
+            }
+        });
+        quote! {
+            impl #krate::__private::Layer<#inner_type> for #struct_name {
+                fn layer(&self) -> &#inner_type {
+                    &self.#inner_ident
+                }
+            }
+
+            #borrowed_layer
+        }
+    };
+    let layer_impl = quote! {
+        impl #krate::__private::Layer<#struct_name> for #struct_name {
+            fn layer(&self) -> &Self {
+                self
+            }
+        }
+
+        #layer_impl
+    };
+
+    // Shared pointers cannot hand out `&mut` to a borrowed `deref` target.
+    let deref_mut_impl = if mutable
+        && !skip_deref
+        && !skip_deref_mut
+        && borrowed.is_none()
+        && (deref_target.is_none() || is_owned_buffer(&inner_type))
+    {
+        quote! {
+            // DerefMut implementation
+            impl ::core::ops::DerefMut for #struct_name {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.#inner_ident
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let display_impl = if !skip_display {
+        quote! {
+            impl ::core::fmt::Display for #struct_name
+            where #display_bound
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::write!(f, "{}", self.#inner_ident)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let debug_impl = if redact_debug {
+        let redacted = format!("{struct_name}(<redacted>)");
+        quote! {
+            impl ::core::fmt::Debug for #struct_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(#redacted)
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl ::core::fmt::Debug for #struct_name
+            where #debug_bound
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::write!(f, "{:?}", self.#inner_ident)
+                }
+            }
+        }
+    };
+
+    let case_fns = case_insensitive.map(|mode| {
+        let (eq, cmp, hash) = match mode {
+            CaseMode::Ascii => (
+                quote! { eq_ascii },
+                quote! { cmp_ascii },
+                quote! { hash_ascii },
+            ),
+            CaseMode::Unicode => (
+                quote! { eq_unicode },
+                quote! { cmp_unicode },
+                quote! { hash_unicode },
+            ),
+        };
+        let case = quote! { #krate::__private::case };
+        (
+            quote! { #case::#eq },
+            quote! { #case::#cmp },
+            quote! { #case::#hash },
+        )
+    });
+
+    let partial_eq_impl = if let (false, Some((eq, _, _))) = (skip_eq, &case_fns) {
+        quote! {
+            impl ::core::cmp::PartialEq for #struct_name {
+                fn eq(&self, other: &Self) -> bool {
+                    #eq(::core::convert::AsRef::<str>::as_ref(&self.#inner_ident), ::core::convert::AsRef::<str>::as_ref(&other.#inner_ident))
+                }
+            }
+        }
+    } else if !skip_eq {
+        quote! {
+            impl ::core::cmp::PartialEq for #struct_name
+            where #partial_eq_bound
+            {
+                fn eq(&self, other: &Self) -> bool {
+                    self.#inner_ident == other.#inner_ident
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // `CompareInner` covers `str` itself, honouring the case mode.
+    let str_eq_impl = match &case_fns {
+        Some((eq, _, _)) if !skip_eq && !compare_inner => quote! {
+            impl ::core::cmp::PartialEq<str> for #struct_name {
+                fn eq(&self, other: &str) -> bool {
+                    #eq(::core::convert::AsRef::<str>::as_ref(&self.#inner_ident), other)
+                }
+            }
+        },
+        _ => quote! {},
+    };
+
+    let compare_inner_impl = if compare_inner {
+        CompareInner {
+            krate: &krate,
+            struct_name,
+            inner_ident: &inner_ident,
+            inner_type: &inner_type,
+            is_string,
+            case_fns: case_fns.as_ref().map(|(eq, cmp, _)| (eq, cmp)),
+            eq: !skip_eq,
+            ord: !skip_ord,
+        }
+        .impls()
+    } else {
+        quote! {}
+    };
+
+    let borrow_impl = if borrow.is_some() {
+        borrow::borrow_impls(struct_name, &inner_ident, &inner_type, is_string)
+    } else {
+        quote! {}
+    };
+
+    let equivalent_impl = match case_insensitive {
+        Some(mode) if cfg!(feature = "equivalent") && !skip_eq => {
+            borrow::equivalent_impl(&krate, struct_name, &inner_ident, mode)
+        }
+        _ => quote! {},
+    };
+
+    let eq_impl = if !skip_eq {
+        quote! {
+            impl ::core::cmp::Eq for #struct_name where #eq_bound {}
+        }
+    } else {
+        quote! {}
+    };
+
+    let partial_ord_impl = if let (false, Some(_)) = (skip_ord, &case_fns) {
+        quote! {
+            impl ::core::cmp::PartialOrd for #struct_name {
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                }
+            }
+        }
+    } else if !skip_ord {
+        quote! {
+            impl ::core::cmp::PartialOrd for #struct_name
+            where #partial_ord_bound
+            {
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::cmp::PartialOrd::partial_cmp(&self.#inner_ident, &other.#inner_ident)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let ord_impl = if let (false, Some((_, cmp, _))) = (skip_ord, &case_fns) {
+        quote! {
+            impl ::core::cmp::Ord for #struct_name {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    #cmp(::core::convert::AsRef::<str>::as_ref(&self.#inner_ident), ::core::convert::AsRef::<str>::as_ref(&other.#inner_ident))
+                }
+            }
+        }
+    } else if !skip_ord {
+        quote! {
+            impl ::core::cmp::Ord for #struct_name
+            where #ord_bound
+            {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    ::core::cmp::Ord::cmp(&self.#inner_ident, &other.#inner_ident)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let hash_impl = if let (false, Some((_, _, hash))) = (skip_hash, &case_fns) {
+        quote! {
+            impl ::core::hash::Hash for #struct_name {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    #hash(::core::convert::AsRef::<str>::as_ref(&self.#inner_ident), state);
+                }
+            }
+        }
+    } else if !skip_hash {
+        quote! {
+            impl ::core::hash::Hash for #struct_name
+            where #hash_bound
+            {
+                fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(&self.#inner_ident, state);
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let clone_impl = if !skip_clone {
+        // A `Symbol` is a handle, so interned types are as cheap to copy.
+        let copy_impl = interned.as_ref().map(|_| {
+            quote! {
+                impl ::core::marker::Copy for #struct_name {}
+            }
+        });
+        quote! {
+            #copy_impl
+
+            impl ::core::clone::Clone for #struct_name
+            where #clone_bound
+            {
+                fn clone(&self) -> Self {
+                    Self {
+                        #inner_ident: ::core::clone::Clone::clone(&self.#inner_ident),
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let serde_impl = if !skip_serde {
+        let deserialized = if validated {
+            quote! {
+                Self::try_new(value).map_err(<D::Error as #krate::__private::serde::de::Error>::custom)
+            }
+        } else {
+            quote! { ::core::result::Result::Ok(Self::new(value)) }
+        };
+        let deserialize_impl = public_constructor.then(|| {
+            quote! {
+                impl<'de> #krate::__private::serde::Deserialize<'de> for #struct_name
+                where #deserialize_bound
+                {
+                    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                    where
+                        D: #krate::__private::serde::Deserializer<'de>,
+                    {
+                        let value = <#source_type as #krate::__private::serde::Deserialize>::deserialize(deserializer)?;
+                        #deserialized
+                    }
+                }
+            }
+        });
+        quote! {
+            impl #krate::__private::serde::Serialize for #struct_name
+            where #serialize_bound
+            {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: #krate::__private::serde::Serializer,
+                {
+                    #krate::__private::serde::Serialize::serialize(&self.#inner_ident, serializer)
+                }
+            }
+
+            #deserialize_impl
+        }
+    } else {
+        quote! {}
+    };
+
+    // Generate all the trait implementations
+    let expanded = quote! {
+        #constructor_impl
+        #new_unchecked_impl
+        #ref_cast_impl
+        #transform_impl
+        #conversions_impl
+        #from_impl
+
+        #normalize_impl
+
+        #validation_impl
+
+        #literal_impl
+
+        #as_ref_impl
+        #layer_impl
+
+        #string_sources_impl
+        #borrowed_impl
+        #appends_impl
+
+        #into_impl
+        #accessors_impl
+        #as_mut_impl
+
+        #deref_impl
+        #deref_mut_impl
+
+        #modify_impl
+
+        #display_impl
+
+        #debug_impl
+
+        #from_str_impl
+
+        #partial_eq_impl
+        #str_eq_impl
+        #eq_impl
+
+        #partial_ord_impl
+        #ord_impl
+
+        #hash_impl
+
+        #compare_inner_impl
+
+        #borrow_impl
+        #equivalent_impl
+
+        #clone_impl
+
+        #serde_impl
+    };
+
+    expanded.into()
+}
+
+#[derive(Clone, Copy)]
+enum CaseMode {
+    Ascii,
+    Unicode,
+}
+
+/// The `FromStr` error of a `StringHolda`.
+#[derive(Clone, Copy)]
+enum FromStrError {
+    Eyre,
+    Validation,
+}
+
+/// The `Default` in a `#[derive(...)]` among `attrs`, if any.
+fn derived_default(attrs: &[syn::Attribute]) -> Option<Path> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(
+                syn::punctuated::Punctuated::<Path, syn::Token![,]>::parse_terminated,
+            )
+            .ok()
+        })
+        .flatten()
+        .find(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Default")
+        })
+}
+
+fn is_primitive(ty: &Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+        "i128", "isize", "f32", "f64",
+    ];
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .get_ident()
+            .is_some_and(|ident| PRIMITIVES.iter().any(|primitive| ident == primitive)),
+        _ => false,
+    }
+}
+
+/// Whether `ty` uniquely owns the buffer a `deref` target borrows from.
+fn is_owned_buffer(ty: &Type) -> bool {
+    const OWNED: &[&str] = &["String", "Vec", "Box", "PathBuf", "OsString"];
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| OWNED.iter().any(|owned| segment.ident == owned)),
+        _ => false,
+    }
+}
@@ -0,0 +1,93 @@
+//! `#[holda::newtype(...)]`: an attribute form of the derives that also
+//! rewrites the struct, which a derive cannot do.
+
+use proc_macro2::TokenStream;
+use proc_macro2::TokenTree;
+use quote::quote;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::Visibility;
+use syn::parse_quote;
+
+use crate::sources::string_kind;
+
+/// Makes the inner field private, adds `#[repr(transparent)]` and
+/// `#[must_use]` unless already present, implements `Copy` when `Copy` is
+/// among `args`, then expands like the matching derive with the remaining
+/// `args` as its options.
+pub fn newtype(args: TokenStream, mut item: DeriveInput) -> syn::Result<TokenStream> {
+    let syn::Data::Struct(data) = &mut item.data else {
+        return Err(syn::Error::new_spanned(
+            &item.ident,
+            "`newtype` only supports structs",
+        ));
+    };
+    let Fields::Named(fields) = &mut data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "`newtype` needs a single named field such as `inner`",
+        ));
+    };
+    if fields.named.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            &fields.named,
+            "`newtype` needs a single named field such as `inner`",
+        ));
+    }
+    let field = fields.named.first_mut().expect("checked above");
+    field.vis = Visibility::Inherited;
+    let is_string = string_kind(&field.ty).is_some();
+
+    let (copy, options) = take_copy(args);
+    let has_attr =
+        |item: &DeriveInput, name: &str| item.attrs.iter().any(|attr| attr.path().is_ident(name));
+    if !has_attr(&item, "repr") {
+        item.attrs.push(parse_quote!(#[repr(transparent)]));
+    }
+    if !has_attr(&item, "must_use") {
+        item.attrs.push(parse_quote!(#[must_use]));
+    }
+
+    // The derive reads the options from `#[holda(...)]`, which cannot stay on
+    // the emitted struct without a derive to own it.
+    let mut derive_input = item.clone();
+    derive_input.attrs.push(parse_quote!(#[holda(#options)]));
+    item.attrs.retain(|attr| !attr.path().is_ident("holda"));
+    let impls = TokenStream::from(crate::expand(derive_input, is_string));
+
+    let name = &item.ident;
+    let copy_impl = if copy {
+        quote! {
+            impl ::core::marker::Copy for #name {}
+        }
+    } else {
+        quote! {}
+    };
+    Ok(quote! {
+        #item
+        #copy_impl
+        #impls
+    })
+}
+
+/// Removes a top-level `Copy` from the comma-separated `args`, returning
+/// whether `newtype` should implement it. `interned` types implement `Copy`
+/// already.
+fn take_copy(args: TokenStream) -> (bool, TokenStream) {
+    let mut options = vec![Vec::new()];
+    for tree in args {
+        match &tree {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => options.push(Vec::new()),
+            _ => options.last_mut().expect("never empty").push(tree),
+        }
+    }
+    let is_flag = |option: &Vec<TokenTree>, name: &str| matches!(option.as_slice(), [TokenTree::Ident(ident)] if ident == name);
+    let is_copy = |option: &Vec<TokenTree>| is_flag(option, "Copy");
+    let copy =
+        options.iter().any(is_copy) && !options.iter().any(|option| is_flag(option, "interned"));
+    let options = options
+        .into_iter()
+        .filter(|option| !option.is_empty() && !is_copy(option))
+        .map(|option| option.into_iter().collect::<TokenStream>());
+    (copy, quote! { #(#options),* })
+}
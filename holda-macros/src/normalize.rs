@@ -0,0 +1,116 @@
+//! `normalize(...)` steps applied before validation on every construction path.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+use syn::Token;
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+
+enum Step {
+    Trim,
+    Lowercase,
+    Nfc,
+    CollapseWhitespace,
+    /// A `fn(Inner) -> Inner`.
+    Custom(Path),
+}
+
+#[derive(Default)]
+pub struct Normalizers {
+    steps: Vec<Step>,
+}
+
+impl Normalizers {
+    /// Consumes `meta` if it is a `normalize` option, returning whether it did.
+    pub fn parse(&mut self, meta: &ParseNestedMeta, is_string: bool) -> syn::Result<bool> {
+        if !meta.path.is_ident("normalize") {
+            return Ok(false);
+        }
+        if meta.input.peek(Token![=]) {
+            self.steps.push(Step::Custom(meta.value()?.parse()?));
+            return Ok(true);
+        }
+        let content;
+        syn::parenthesized!(content in meta.input);
+        for path in Punctuated::<Path, Token![,]>::parse_terminated(&content)? {
+            let step = if path.is_ident("trim") {
+                Step::Trim
+            } else if path.is_ident("lowercase") {
+                Step::Lowercase
+            } else if path.is_ident("nfc") {
+                if cfg!(not(feature = "nfc")) {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        "`nfc` requires the `nfc` feature of holda",
+                    ));
+                }
+                Step::Nfc
+            } else if path.is_ident("collapse_whitespace") {
+                Step::CollapseWhitespace
+            } else {
+                Step::Custom(path)
+            };
+            if !is_string && !matches!(step, Step::Custom(_)) {
+                return Err(
+                    meta.error("built-in normalize steps are only supported by StringHolda")
+                );
+            }
+            self.steps.push(step);
+        }
+        Ok(true)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Whether some step cannot be checked during const evaluation.
+    pub fn has_runtime_steps(&self) -> bool {
+        self.steps
+            .iter()
+            .any(|step| matches!(step, Step::Nfc | Step::Custom(_)))
+    }
+
+    /// Statements rewriting the `Inner` binding `value`, in declaration order.
+    pub fn steps(&self, krate: &Path) -> TokenStream {
+        let steps = self.steps.iter().map(|step| {
+            let builtin = match step {
+                Step::Trim => quote! { trim },
+                Step::Lowercase => quote! { lowercase },
+                Step::Nfc => quote! { nfc },
+                Step::CollapseWhitespace => quote! { collapse_whitespace },
+                Step::Custom(path) => return quote! { let value = #path(value); },
+            };
+            quote! {
+                let value = match #krate::__private::normalize::#builtin(::core::convert::AsRef::<str>::as_ref(&value)) {
+                    ::core::option::Option::Some(normalized) => ::core::convert::Into::into(normalized),
+                    ::core::option::Option::None => value,
+                };
+            }
+        });
+        quote! { #(#steps)* }
+    }
+
+    /// `const` statements rejecting a `&str` literal `value` that the steps
+    /// would change, where that can be decided at compile time.
+    pub fn literal_checks(&self, krate: &Path) -> TokenStream {
+        let checks = self.steps.iter().filter_map(|step| {
+            let (check, message) = match step {
+                Step::Trim => (quote! { is_trimmed }, "literal has surrounding whitespace"),
+                Step::Lowercase => (quote! { is_lowercase }, "literal is not lowercase"),
+                Step::CollapseWhitespace => (
+                    quote! { is_collapsed },
+                    "literal contains whitespace that is not a single space",
+                ),
+                Step::Nfc | Step::Custom(_) => return None,
+            };
+            Some(quote! {
+                if !#krate::__private::normalize::#check(value) {
+                    ::core::panic!(#message);
+                }
+            })
+        });
+        quote! { #(#checks)* }
+    }
+}
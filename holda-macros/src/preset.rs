@@ -0,0 +1,157 @@
+//! `#[holda(only(...))]` and `#[holda(preset = "...")]`: opt-in trait sets,
+//! so traits added to the macro later do not change existing types.
+
+use proc_macro2::Ident;
+use syn::LitStr;
+use syn::Token;
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+
+/// Traits an allowlist can name: one for each `No*` flag, then the groups of
+/// generated methods. `Transform` covers `map_inner`, `try_map_inner` and
+/// `map_into`, and `Mutate` covers `inner_mut`, `modify`, `try_modify`,
+/// `replace_inner`, `fmt::Write` and `Extend`.
+const TRAITS: &[&str] = &[
+    "Display",
+    "Eq",
+    "Ord",
+    "Hash",
+    "Clone",
+    "Serde",
+    "Deref",
+    "DerefMut",
+    "From",
+    "Into",
+    "AsRef",
+    "FromStr",
+    "Transform",
+    "Mutate",
+];
+
+/// Entries that generate impls with supertraits covered by other entries:
+/// `Ord` needs the `PartialEq` and `Eq` impls of `Eq`.
+const REQUIRES: &[(&str, &str)] = &[("Ord", "Eq")];
+
+struct Preset {
+    name: &'static str,
+    traits: &'static [&'static str],
+    /// Print `Name(<redacted>)` instead of the inner value.
+    redact_debug: bool,
+}
+
+/// The trait set of every preset. These are part of the public interface:
+/// changing a set is a breaking change, so new traits are never added to an
+/// existing preset.
+const PRESETS: &[Preset] = &[
+    Preset {
+        name: "id",
+        traits: &[
+            "Display", "Eq", "Ord", "Hash", "Clone", "Serde", "From", "Into", "AsRef", "FromStr",
+        ],
+        redact_debug: false,
+    },
+    Preset {
+        name: "name",
+        traits: &[
+            "Display",
+            "Eq",
+            "Ord",
+            "Hash",
+            "Clone",
+            "Serde",
+            "Deref",
+            "From",
+            "Into",
+            "AsRef",
+            "FromStr",
+            "Transform",
+        ],
+        redact_debug: false,
+    },
+    Preset {
+        name: "quantity",
+        traits: &[
+            "Display",
+            "Eq",
+            "Ord",
+            "Clone",
+            "Serde",
+            "Deref",
+            "From",
+            "Into",
+            "AsRef",
+            "FromStr",
+            "Transform",
+            "Mutate",
+        ],
+        redact_debug: false,
+    },
+    Preset {
+        name: "secret",
+        traits: &["Eq", "Clone", "From"],
+        redact_debug: true,
+    },
+];
+
+pub struct Allowlist {
+    traits: Vec<String>,
+    pub redact_debug: bool,
+}
+
+impl Allowlist {
+    /// Parses `only(...)` or `preset = "..."`, returning `None` for any other
+    /// option.
+    pub fn parse(meta: &ParseNestedMeta) -> syn::Result<Option<Self>> {
+        if meta.path.is_ident("only") {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            let names = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            let mut traits = Vec::new();
+            for name in &names {
+                let name_str = name.to_string();
+                if !TRAITS.contains(&name_str.as_str()) {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("expected one of {}", TRAITS.join(", ")),
+                    ));
+                }
+                traits.push(name_str);
+            }
+            for name in &names {
+                for (entry, required) in REQUIRES {
+                    if name == entry && !traits.iter().any(|allowed| allowed == required) {
+                        return Err(syn::Error::new(
+                            name.span(),
+                            format!(
+                                "`{entry}` requires `{required}`, which is missing from `only(...)`"
+                            ),
+                        ));
+                    }
+                }
+            }
+            Ok(Some(Self {
+                traits,
+                redact_debug: false,
+            }))
+        } else if meta.path.is_ident("preset") {
+            let lit: LitStr = meta.value()?.parse()?;
+            let Some(preset) = PRESETS.iter().find(|preset| preset.name == lit.value()) else {
+                let names: Vec<_> = PRESETS.iter().map(|preset| preset.name).collect();
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("unknown preset; expected one of {}", names.join(", ")),
+                ));
+            };
+            Ok(Some(Self {
+                traits: preset.traits.iter().map(|name| name.to_string()).collect(),
+                redact_debug: preset.redact_debug,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn allows(&self, name: &str) -> bool {
+        self.traits.iter().any(|allowed| allowed == name)
+    }
+}
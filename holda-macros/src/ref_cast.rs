@@ -0,0 +1,100 @@
+//! `#[holda(ref_cast)]`: views of borrowed inner values as the wrapper,
+//! relying on `#[repr(transparent)]` for the layout.
+
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use proc_macro2::TokenTree;
+use quote::quote;
+use syn::Attribute;
+use syn::Meta;
+use syn::Path;
+use syn::Type;
+use syn::Visibility;
+
+pub struct RefCast<'a> {
+    pub krate: &'a Path,
+    pub struct_name: &'a Ident,
+    pub inner_type: &'a Type,
+    pub constructor_vis: &'a Visibility,
+    pub validated: bool,
+}
+
+/// Whether `attrs` contain `#[repr(transparent)]`, which a derive can check
+/// but not add.
+pub fn is_transparent(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| match &attr.meta {
+        Meta::List(list) if list.path.is_ident("repr") => list
+            .tokens
+            .clone()
+            .into_iter()
+            .any(|token| matches!(token, TokenTree::Ident(ident) if ident == "transparent")),
+        _ => false,
+    })
+}
+
+impl RefCast<'_> {
+    /// `from_ref`, `from_mut` and `from_slice`. Validated types check every
+    /// value before the cast and return a `Result`.
+    pub fn impls(&self) -> TokenStream {
+        let krate = self.krate;
+        let struct_name = self.struct_name;
+        let inner_type = self.inner_type;
+        // The casts build values from arbitrary inners, so they are no more
+        // visible than the constructor.
+        let vis = self.constructor_vis;
+        // SAFETY (for every cast below): the struct is `repr(transparent)`
+        // over the inner type, so both have the same layout, and the
+        // lifetime and mutability of the borrow carry over unchanged.
+        let cast_ref = quote! { unsafe { &*(inner as *const #inner_type as *const Self) } };
+        let cast_mut = quote! { unsafe { &mut *(inner as *mut #inner_type as *mut Self) } };
+        let cast_slice = quote! { unsafe { &*(inner as *const [#inner_type] as *const [Self]) } };
+        if self.validated {
+            quote! {
+                impl #struct_name {
+                    /// Views `inner` as this type without copying it, if it
+                    /// passes validation.
+                    #vis fn from_ref(inner: &#inner_type) -> ::core::result::Result<&Self, #krate::ValidationError> {
+                        Self::__holda_validate(inner)?;
+                        ::core::result::Result::Ok(#cast_ref)
+                    }
+
+                    /// Views `inner` as this type without copying it, if it
+                    /// passes validation.
+                    #vis fn from_mut(inner: &mut #inner_type) -> ::core::result::Result<&mut Self, #krate::ValidationError> {
+                        Self::__holda_validate(inner)?;
+                        ::core::result::Result::Ok(#cast_mut)
+                    }
+
+                    /// Views `inner` as a slice of this type without copying
+                    /// it, if every value passes validation.
+                    #vis fn from_slice(inner: &[#inner_type]) -> ::core::result::Result<&[Self], #krate::ValidationError> {
+                        for value in inner {
+                            Self::__holda_validate(value)?;
+                        }
+                        ::core::result::Result::Ok(#cast_slice)
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #struct_name {
+                    /// Views `inner` as this type without copying it.
+                    #vis const fn from_ref(inner: &#inner_type) -> &Self {
+                        #cast_ref
+                    }
+
+                    /// Views `inner` as this type without copying it.
+                    #vis const fn from_mut(inner: &mut #inner_type) -> &mut Self {
+                        #cast_mut
+                    }
+
+                    /// Views `inner` as a slice of this type without copying
+                    /// it.
+                    #vis const fn from_slice(inner: &[#inner_type]) -> &[Self] {
+                        #cast_slice
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//! Compiles `regex = "..."` and `charset = "..."` patterns into the program
+//! format executed by `holda::__private::regex::is_match`.
+//!
+//! Supported syntax: literals, `.`, `[...]` classes with ranges and negation,
+//! `\d \w \s` (ASCII) and their negations, `^`, `$`, groups, alternation and
+//! the `* + ? {n} {n,} {n,m}` quantifiers.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Must agree with `holda::__private::regex::MAX_INSTS`.
+const MAX_INSTS: usize = 512;
+
+const MAX_CHAR: char = char::MAX;
+
+type Ranges = Vec<(char, char)>;
+
+enum Node {
+    Class(Ranges),
+    Start,
+    End,
+    Concat(Vec<Node>),
+    Alt(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+enum Inst {
+    Class(Ranges),
+    Split(usize, usize),
+    Jmp(usize),
+    Start,
+    End,
+    Match,
+}
+
+/// A compiled pattern, ready to be emitted as a `&[Inst]` expression.
+pub struct Program {
+    insts: Vec<Inst>,
+}
+
+pub fn compile(pattern: &str) -> Result<Program, String> {
+    let mut parser = Parser {
+        chars: pattern.chars().collect(),
+        pos: 0,
+    };
+    let node = parser.parse_alt()?;
+    if parser.pos < parser.chars.len() {
+        return Err(format!("unmatched `)` at offset {}", parser.pos));
+    }
+    let mut insts = Vec::new();
+    emit(&node, &mut insts)
+        .and_then(|()| push(&mut insts, Inst::Match))
+        .map_err(|TooLong| format!("pattern compiles to more than {MAX_INSTS} instructions"))?;
+    Ok(Program { insts })
+}
+
+/// Parses the inside of a bracket class, e.g. `a-z0-9_`, into ranges.
+pub fn compile_charset(charset: &str) -> Result<Ranges, String> {
+    let mut chars: Vec<char> = charset.chars().collect();
+    chars.push(']');
+    let mut parser = Parser { chars, pos: 0 };
+    let ranges = parser.parse_class_items()?;
+    if ranges.is_empty() {
+        return Err("charset must not be empty".to_string());
+    }
+    Ok(ranges)
+}
+
+pub fn ranges_tokens(ranges: &[(char, char)]) -> TokenStream {
+    let items = ranges.iter().map(|(lo, hi)| quote! { (#lo, #hi) });
+    quote! { &[#(#items),*] }
+}
+
+impl Program {
+    pub fn to_tokens(&self, inst_path: &TokenStream) -> TokenStream {
+        let insts = self.insts.iter().map(|inst| match inst {
+            Inst::Class(ranges) => {
+                let ranges = ranges_tokens(ranges);
+                quote! { #inst_path::Class(#ranges) }
+            }
+            Inst::Split(a, b) => quote! { #inst_path::Split(#a, #b) },
+            Inst::Jmp(target) => quote! { #inst_path::Jmp(#target) },
+            Inst::Start => quote! { #inst_path::Start },
+            Inst::End => quote! { #inst_path::End },
+            Inst::Match => quote! { #inst_path::Match },
+        });
+        quote! { &[#(#insts),*] }
+    }
+}
+
+/// The program outgrew `MAX_INSTS`.
+struct TooLong;
+
+fn push(insts: &mut Vec<Inst>, inst: Inst) -> Result<(), TooLong> {
+    if insts.len() == MAX_INSTS {
+        return Err(TooLong);
+    }
+    insts.push(inst);
+    Ok(())
+}
+
+/// Appends the instructions for `node`, stopping as soon as the program
+/// outgrows `MAX_INSTS`, since nested counted repetitions multiply.
+fn emit(node: &Node, insts: &mut Vec<Inst>) -> Result<(), TooLong> {
+    match node {
+        Node::Class(ranges) => push(insts, Inst::Class(ranges.clone()))?,
+        Node::Start => push(insts, Inst::Start)?,
+        Node::End => push(insts, Inst::End)?,
+        Node::Concat(nodes) => {
+            for node in nodes {
+                emit(node, insts)?;
+            }
+        }
+        Node::Alt(nodes) => {
+            let mut jumps = Vec::new();
+            for (i, node) in nodes.iter().enumerate() {
+                if i + 1 < nodes.len() {
+                    let split = insts.len();
+                    push(insts, Inst::Split(split + 1, 0))?;
+                    emit(node, insts)?;
+                    jumps.push(insts.len());
+                    push(insts, Inst::Jmp(0))?;
+                    let next = insts.len();
+                    insts[split] = Inst::Split(split + 1, next);
+                } else {
+                    emit(node, insts)?;
+                }
+            }
+            let end = insts.len();
+            for jump in jumps {
+                insts[jump] = Inst::Jmp(end);
+            }
+        }
+        Node::Repeat { node, min, max } => {
+            for _ in 0..*min {
+                let before = insts.len();
+                emit(node, insts)?;
+                // Repeating an empty group, e.g. `(){512}`, adds nothing.
+                if insts.len() == before {
+                    break;
+                }
+            }
+            match max {
+                None => {
+                    let split = insts.len();
+                    push(insts, Inst::Split(split + 1, 0))?;
+                    emit(node, insts)?;
+                    push(insts, Inst::Jmp(split))?;
+                    let end = insts.len();
+                    insts[split] = Inst::Split(split + 1, end);
+                }
+                Some(max) => {
+                    let mut splits = Vec::new();
+                    for _ in *min..*max {
+                        splits.push(insts.len());
+                        push(insts, Inst::Split(0, 0))?;
+                        emit(node, insts)?;
+                    }
+                    let end = insts.len();
+                    for split in splits {
+                        insts[split] = Inst::Split(split + 1, end);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peek();
+        self.pos += 1;
+        ch
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_alt(&mut self) -> Result<Node, String> {
+        let mut branches = vec![self.parse_concat()?];
+        while self.eat('|') {
+            branches.push(self.parse_concat()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap()
+        } else {
+            Node::Alt(branches)
+        })
+    }
+
+    fn parse_concat(&mut self) -> Result<Node, String> {
+        let mut nodes = Vec::new();
+        while let Some(ch) = self.peek() {
+            if ch == '|' || ch == ')' {
+                break;
+            }
+            let atom = self.parse_atom()?;
+            nodes.push(self.parse_repeat(atom)?);
+        }
+        Ok(Node::Concat(nodes))
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, String> {
+        let start = self.pos;
+        match self.next().unwrap() {
+            '(' => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err(format!("unsupported group syntax at offset {start}"));
+                }
+                let node = self.parse_alt()?;
+                if !self.eat(')') {
+                    return Err(format!("unclosed group opened at offset {start}"));
+                }
+                Ok(node)
+            }
+            '[' => {
+                let negated = self.eat('^');
+                let ranges = self.parse_class_items()?;
+                Ok(Node::Class(if negated { negate(&ranges) } else { ranges }))
+            }
+            '.' => Ok(Node::Class(negate(&[('\n', '\n')]))),
+            '^' => Ok(Node::Start),
+            '$' => Ok(Node::End),
+            '\\' => Ok(Node::Class(self.parse_escape()?)),
+            ch @ ('*' | '+' | '?' | '{') => Err(format!(
+                "quantifier `{ch}` at offset {start} has nothing to repeat"
+            )),
+            ch => Ok(Node::Class(vec![(ch, ch)])),
+        }
+    }
+
+    fn parse_repeat(&mut self, mut node: Node) -> Result<Node, String> {
+        loop {
+            let (min, max) = match self.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') => {
+                    self.pos += 1;
+                    let min = self.parse_number()?;
+                    let max = if self.eat(',') {
+                        if self.peek() == Some('}') {
+                            None
+                        } else {
+                            Some(self.parse_number()?)
+                        }
+                    } else {
+                        Some(min)
+                    };
+                    if self.peek() != Some('}') {
+                        return Err(format!(
+                            "unclosed counted repetition at offset {}",
+                            self.pos
+                        ));
+                    }
+                    if max.is_some_and(|max| max < min) {
+                        return Err(format!("invalid repetition {{{min},{}}}", max.unwrap()));
+                    }
+                    if max.unwrap_or(min) as usize > MAX_INSTS {
+                        return Err(format!("repetition count exceeds {MAX_INSTS}"));
+                    }
+                    (min, max)
+                }
+                _ => return Ok(node),
+            };
+            self.pos += 1;
+            // Laziness does not change whether a match exists.
+            self.eat('?');
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+            };
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<u32, String> {
+        let start = self.pos;
+        while self.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits: String = self.chars[start..self.pos].iter().collect();
+        digits
+            .parse()
+            .map_err(|_| format!("expected a number at offset {start}"))
+    }
+
+    fn parse_escape(&mut self) -> Result<Ranges, String> {
+        let start = self.pos;
+        let Some(ch) = self.next() else {
+            return Err("pattern ends with a trailing `\\`".to_string());
+        };
+        Ok(match ch {
+            'd' => digit(),
+            'D' => negate(&digit()),
+            'w' => word(),
+            'W' => negate(&word()),
+            's' => space(),
+            'S' => negate(&space()),
+            'n' => vec![('\n', '\n')],
+            'r' => vec![('\r', '\r')],
+            't' => vec![('\t', '\t')],
+            ch if ch.is_ascii_punctuation() || ch == ' ' => vec![(ch, ch)],
+            ch => return Err(format!("unsupported escape `\\{ch}` at offset {start}")),
+        })
+    }
+
+    /// Parses class items up to and including the closing `]`.
+    fn parse_class_items(&mut self) -> Result<Ranges, String> {
+        let start = self.pos;
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let Some(ch) = self.next() else {
+                return Err(format!("unclosed character class at offset {start}"));
+            };
+            let lo = match ch {
+                ']' if !first => break,
+                '\\' => {
+                    let escaped = self.parse_escape()?;
+                    if escaped.len() != 1 || escaped[0].0 != escaped[0].1 {
+                        ranges.extend(escaped);
+                        first = false;
+                        continue;
+                    }
+                    escaped[0].0
+                }
+                ch => ch,
+            };
+            first = false;
+            if self.peek() == Some('-') && self.chars.get(self.pos + 1) != Some(&']') {
+                self.pos += 1;
+                let hi = match self.next() {
+                    Some('\\') => {
+                        let escaped = self.parse_escape()?;
+                        if escaped.len() != 1 || escaped[0].0 != escaped[0].1 {
+                            return Err(format!("invalid range end at offset {}", self.pos));
+                        }
+                        escaped[0].0
+                    }
+                    Some(hi) => hi,
+                    None => return Err(format!("unclosed character class at offset {start}")),
+                };
+                if hi < lo {
+                    return Err(format!("invalid range `{lo}-{hi}`"));
+                }
+                ranges.push((lo, hi));
+            } else {
+                ranges.push((lo, lo));
+            }
+        }
+        Ok(normalize(ranges))
+    }
+}
+
+fn digit() -> Ranges {
+    vec![('0', '9')]
+}
+
+fn word() -> Ranges {
+    vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')]
+}
+
+fn space() -> Ranges {
+    vec![('\t', '\r'), (' ', ' ')]
+}
+
+fn normalize(mut ranges: Ranges) -> Ranges {
+    ranges.sort();
+    let mut merged: Ranges = Vec::new();
+    for (lo, hi) in ranges {
+        if let Some(last) = merged.last_mut()
+            && lo as u32 <= last.1 as u32 + 1
+        {
+            last.1 = last.1.max(hi);
+            continue;
+        }
+        merged.push((lo, hi));
+    }
+    merged
+}
+
+fn negate(ranges: &[(char, char)]) -> Ranges {
+    let mut negated = Vec::new();
+    let mut next = 0u32;
+    for &(lo, hi) in &normalize(ranges.to_vec()) {
+        if (lo as u32) > next {
+            push_scalar_range(&mut negated, next, lo as u32 - 1);
+        }
+        next = hi as u32 + 1;
+    }
+    if next <= MAX_CHAR as u32 {
+        push_scalar_range(&mut negated, next, MAX_CHAR as u32);
+    }
+    negated
+}
+
+/// Pushes `lo..=hi`, skipping the surrogate block which holds no `char`s.
+fn push_scalar_range(ranges: &mut Ranges, lo: u32, hi: u32) {
+    let lo = if (0xD800..=0xDFFF).contains(&lo) {
+        0xE000
+    } else {
+        lo
+    };
+    let hi = if (0xD800..=0xDFFF).contains(&hi) {
+        0xD7FF
+    } else {
+        hi
+    };
+    if lo > hi {
+        return;
+    }
+    if lo < 0xD800 && hi > 0xDFFF {
+        ranges.push((char::from_u32(lo).unwrap(), '\u{D7FF}'));
+        ranges.push(('\u{E000}', char::from_u32(hi).unwrap()));
+    } else {
+        ranges.push((char::from_u32(lo).unwrap(), char::from_u32(hi).unwrap()));
+    }
+}
@@ -0,0 +1,197 @@
+//! String-source conversions for StringHolda, plus `fmt::Write` and `Extend`
+//! for mutable `String` inners.
+
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::GenericArgument;
+use syn::Path;
+use syn::PathArguments;
+use syn::Type;
+
+/// How a string inner type is built from a `str`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum StringKind {
+    /// Implements `holda::__private::StringInner`.
+    Unbounded,
+    /// A fixed-capacity string such as `heapless::String<N>`, which
+    /// implements `holda::__private::BoundedString` instead.
+    Bounded,
+}
+
+/// Classifies `ty` by its last path segment: `String`, `Symbol`, `SmolStr`
+/// and `CompactString`, `Box`, `Rc`, `Arc` and `Cow` over `str`, or a
+/// `String` with generic arguments. `None` for any other type.
+pub fn string_kind(ty: &Type) -> Option<StringKind> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+    let segment = path.path.segments.last()?;
+    let is_unbounded = match &segment.arguments {
+        PathArguments::None => ["String", "Symbol", "SmolStr", "CompactString"]
+            .iter()
+            .any(|name| segment.ident == name),
+        PathArguments::AngleBracketed(_) if segment.ident == "String" => {
+            return Some(StringKind::Bounded);
+        }
+        PathArguments::AngleBracketed(args) => {
+            ["Box", "Rc", "Arc", "Cow"].iter().any(|name| segment.ident == name)
+                && args.args.iter().any(|arg| {
+                    matches!(arg, GenericArgument::Type(Type::Path(path)) if path.path.is_ident("str"))
+                })
+        }
+        PathArguments::Parenthesized(_) => false,
+    };
+    is_unbounded.then_some(StringKind::Unbounded)
+}
+
+pub struct Sources<'a> {
+    pub krate: &'a Path,
+    pub struct_name: &'a Ident,
+    pub inner_ident: &'a Ident,
+    pub inner_type: &'a Type,
+    pub validated: bool,
+}
+
+impl Sources<'_> {
+    /// Whether the inner type implements `StringInner`, so `conversions`
+    /// applies. Other inners only convert from `&str`.
+    pub fn is_string_inner(&self) -> bool {
+        string_kind(self.inner_type) == Some(StringKind::Unbounded)
+    }
+
+    /// `From`, or `TryFrom` for validated types, for every string source the
+    /// inner type does not already cover through `From<Inner>`.
+    pub fn conversions(&self) -> TokenStream {
+        let krate = self.krate;
+        let struct_name = self.struct_name;
+        let inner_type = self.inner_type;
+        let inner_name = match last_segment(inner_type) {
+            Some(name) if self.is_string_inner() => name,
+            _ => return quote! {},
+        };
+        let string_inner = quote! { <#inner_type as #krate::__private::StringInner> };
+        let sources = [
+            (
+                "",
+                quote! {},
+                quote! { &str },
+                quote! { #string_inner::from_borrowed(value) },
+            ),
+            (
+                "String",
+                quote! {},
+                quote! { #krate::__private::alloc::string::String },
+                quote! { #string_inner::from_owned(value) },
+            ),
+            (
+                "Box",
+                quote! {},
+                quote! { #krate::__private::alloc::boxed::Box<str> },
+                quote! { #string_inner::from_owned(value.into_string()) },
+            ),
+            (
+                "Cow",
+                quote! { <'a> },
+                quote! { #krate::__private::alloc::borrow::Cow<'a, str> },
+                quote! {
+                    match value {
+                        #krate::__private::alloc::borrow::Cow::Borrowed(value) => #string_inner::from_borrowed(value),
+                        #krate::__private::alloc::borrow::Cow::Owned(value) => #string_inner::from_owned(value),
+                    }
+                },
+            ),
+            (
+                "",
+                quote! {},
+                quote! { &#krate::__private::alloc::string::String },
+                quote! { #string_inner::from_borrowed(value) },
+            ),
+            (
+                "",
+                quote! {},
+                quote! { char },
+                quote! { #string_inner::from_borrowed(value.encode_utf8(&mut [0; 4])) },
+            ),
+        ];
+        // The constructors of `Symbol` inners take a `String`, which every
+        // source converts into, so nothing is interned before validation.
+        let symbol = is_symbol(inner_type);
+        let impls = sources
+            .into_iter()
+            .filter(|(name, ..)| *name != inner_name)
+            .map(|(_, generics, source, inner)| {
+                let inner = if symbol { quote! { value } } else { inner };
+                if self.validated {
+                    quote! {
+                        impl #generics ::core::convert::TryFrom<#source> for #struct_name {
+                            type Error = #krate::ValidationError;
+
+                            fn try_from(value: #source) -> ::core::result::Result<Self, Self::Error> {
+                                Self::try_new(#inner)
+                            }
+                        }
+                    }
+                } else {
+                    quote! {
+                        impl #generics ::core::convert::From<#source> for #struct_name {
+                            fn from(value: #source) -> Self {
+                                Self::new(#inner)
+                            }
+                        }
+                    }
+                }
+            });
+        quote! { #(#impls)* }
+    }
+
+    /// `fmt::Write`, `Extend<char>` and `Extend<&str>` appending to a `String`
+    /// inner. Only for types whose inner value may be mutated directly.
+    pub fn appends(&self) -> TokenStream {
+        let struct_name = self.struct_name;
+        let inner_ident = self.inner_ident;
+        if !self.is_string_inner() || last_segment(self.inner_type).as_deref() != Some("String") {
+            return quote! {};
+        }
+        quote! {
+            impl ::core::fmt::Write for #struct_name {
+                fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                    self.#inner_ident.push_str(s);
+                    ::core::result::Result::Ok(())
+                }
+            }
+
+            impl ::core::iter::Extend<char> for #struct_name {
+                fn extend<I: ::core::iter::IntoIterator<Item = char>>(&mut self, iter: I) {
+                    ::core::iter::Extend::extend(&mut self.#inner_ident, iter);
+                }
+            }
+
+            impl<'a> ::core::iter::Extend<&'a str> for #struct_name {
+                fn extend<I: ::core::iter::IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+                    ::core::iter::Extend::extend(&mut self.#inner_ident, iter);
+                }
+            }
+        }
+    }
+}
+
+/// Whether `ty` names `holda::Symbol`, whose `Eq` and `Hash` compare the
+/// handle rather than the string.
+pub fn is_symbol(ty: &Type) -> bool {
+    last_segment(ty).as_deref() == Some("Symbol")
+}
+
+fn last_segment(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
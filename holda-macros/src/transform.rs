@@ -0,0 +1,127 @@
+//! `map_inner`, `try_map_inner`, `map_into` and `replace_inner`, each going
+//! through the same constructor as `new` or `try_new`. The names stay clear of
+//! methods reached through `Deref`, such as `str::replace` or `Option::map`.
+
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+use syn::Type;
+use syn::Visibility;
+
+pub struct Transform<'a> {
+    pub krate: &'a Path,
+    pub struct_name: &'a Ident,
+    pub inner_ident: &'a Ident,
+    pub inner_type: &'a Type,
+    /// What `new` and `try_new` take, `String` for a `Symbol` inner.
+    pub source_type: &'a Type,
+    pub constructor_vis: &'a Visibility,
+    pub validated: bool,
+    pub readonly: bool,
+    /// Leaves out `map_inner`, `try_map_inner` and `map_into`.
+    pub skip_map: bool,
+}
+
+impl Transform<'_> {
+    pub fn impls(&self) -> TokenStream {
+        let krate = self.krate;
+        let struct_name = self.struct_name;
+        let inner_ident = self.inner_ident;
+        let inner_type = self.inner_type;
+        let source_type = self.source_type;
+        // `map_inner` and `replace_inner` build values from arbitrary inners, so they are
+        // no more visible than the constructor.
+        let vis = self.constructor_vis;
+        let (map, try_map, replace, output, construct) = if self.validated {
+            (
+                quote! {
+                    #vis fn map_inner(self, f: impl ::core::ops::FnOnce(#inner_type) -> #inner_type) -> ::core::result::Result<Self, #krate::ValidationError> {
+                        Self::try_new(f(self.#inner_ident))
+                    }
+                },
+                quote! {
+                    #vis fn try_map_inner<E>(self, f: impl ::core::ops::FnOnce(#inner_type) -> ::core::result::Result<#inner_type, E>) -> ::core::result::Result<Self, E>
+                    where
+                        E: ::core::convert::From<#krate::ValidationError>,
+                    {
+                        ::core::result::Result::Ok(Self::try_new(f(self.#inner_ident)?)?)
+                    }
+                },
+                quote! {
+                    #vis fn replace_inner(&mut self, value: impl ::core::convert::Into<#source_type>) -> ::core::result::Result<#inner_type, #krate::ValidationError> {
+                        let value = Self::try_new(value)?;
+                        ::core::result::Result::Ok(::core::mem::replace(&mut self.#inner_ident, value.#inner_ident))
+                    }
+                },
+                quote! { ::core::result::Result<Self, #krate::ValidationError> },
+                quote! { Self::try_new(inner) },
+            )
+        } else {
+            (
+                quote! {
+                    #vis fn map_inner(self, f: impl ::core::ops::FnOnce(#inner_type) -> #inner_type) -> Self {
+                        Self::new(f(self.#inner_ident))
+                    }
+                },
+                quote! {
+                    #vis fn try_map_inner<E>(self, f: impl ::core::ops::FnOnce(#inner_type) -> ::core::result::Result<#inner_type, E>) -> ::core::result::Result<Self, E> {
+                        ::core::result::Result::Ok(Self::new(f(self.#inner_ident)?))
+                    }
+                },
+                quote! {
+                    #vis fn replace_inner(&mut self, value: impl ::core::convert::Into<#source_type>) -> #inner_type {
+                        let value = Self::new(value);
+                        ::core::mem::replace(&mut self.#inner_ident, value.#inner_ident)
+                    }
+                },
+                quote! { Self },
+                quote! { Self::new(inner) },
+            )
+        };
+        let replace = if self.readonly {
+            quote! {}
+        } else {
+            replace
+        };
+        let construct_impl = if matches!(vis, Visibility::Public(_)) {
+            quote! {
+                impl #krate::__private::Construct for #struct_name {
+                    type Output = #output;
+
+                    fn construct(inner: #inner_type) -> #output {
+                        #construct
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let maps = if self.skip_map {
+            quote! {}
+        } else {
+            quote! {
+                #map
+
+                #try_map
+
+                /// Maps the inner value into the inner type of `T` and builds a `T` from it.
+                pub fn map_into<T: #krate::__private::Construct>(
+                    self,
+                    f: impl ::core::ops::FnOnce(#inner_type) -> <T as #krate::__private::Wrapper>::Inner,
+                ) -> T::Output {
+                    T::construct(f(self.#inner_ident))
+                }
+            }
+        };
+        quote! {
+            impl #struct_name {
+                #maps
+
+                #replace
+            }
+
+            #construct_impl
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Built-in validators declared through `#[holda(...)]`.
+
+use crate::regex;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Expr;
+use syn::ExprRange;
+use syn::Lit;
+use syn::LitInt;
+use syn::LitStr;
+use syn::Path;
+use syn::RangeLimits;
+use syn::Token;
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+
+#[derive(Default)]
+pub struct Validators {
+    non_empty: bool,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    charset: Option<Vec<(char, char)>>,
+    regex: Option<(String, regex::Program)>,
+    range: Option<ExprRange>,
+    one_of: Option<Vec<Lit>>,
+}
+
+impl Validators {
+    /// Consumes `meta` if it names a validator, returning whether it did.
+    pub fn parse(&mut self, meta: &ParseNestedMeta, is_string: bool) -> syn::Result<bool> {
+        let string_only = |name: &str| {
+            if is_string {
+                Ok(())
+            } else {
+                Err(meta.error(format!("`{name}` is only supported by StringHolda")))
+            }
+        };
+        if meta.path.is_ident("non_empty") {
+            string_only("non_empty")?;
+            self.non_empty = true;
+        } else if meta.path.is_ident("min_len") {
+            string_only("min_len")?;
+            self.min_len = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+        } else if meta.path.is_ident("max_len") {
+            string_only("max_len")?;
+            self.max_len = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+        } else if meta.path.is_ident("charset") {
+            string_only("charset")?;
+            let lit: LitStr = meta.value()?.parse()?;
+            let ranges = regex::compile_charset(&lit.value())
+                .map_err(|err| syn::Error::new(lit.span(), format!("invalid charset: {err}")))?;
+            self.charset = Some(ranges);
+        } else if meta.path.is_ident("regex") {
+            string_only("regex")?;
+            let lit: LitStr = meta.value()?.parse()?;
+            let program = regex::compile(&lit.value())
+                .map_err(|err| syn::Error::new(lit.span(), format!("invalid regex: {err}")))?;
+            self.regex = Some((lit.value(), program));
+        } else if meta.path.is_ident("range") {
+            if is_string {
+                return Err(meta.error("`range` is not supported by StringHolda"));
+            }
+            let Expr::Range(range) = meta.value()?.parse::<Expr>()? else {
+                return Err(meta.error("expected a range such as `1..=100`"));
+            };
+            self.range = Some(range);
+        } else if meta.path.is_ident("one_of") {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            let values = Punctuated::<Lit, Token![,]>::parse_terminated(&content)?;
+            if values.is_empty() {
+                return Err(meta.error("`one_of` needs at least one value"));
+            }
+            if is_string && values.iter().any(|value| !matches!(value, Lit::Str(_))) {
+                return Err(meta.error("StringHolda `one_of` values must be string literals"));
+            }
+            self.one_of = Some(values.into_iter().collect());
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.non_empty
+            && self.min_len.is_none()
+            && self.max_len.is_none()
+            && self.charset.is_none()
+            && self.regex.is_none()
+            && self.range.is_none()
+            && self.one_of.is_none()
+    }
+
+    /// Statements checking `value` (a `&str` for StringHolda, otherwise
+    /// `&Inner`) that return early with a `ValidationErrorKind`.
+    pub fn checks(&self, krate: &Path) -> TokenStream {
+        let kind = quote! { #krate::ValidationErrorKind };
+        let mut checks = Vec::new();
+        if self.non_empty {
+            checks.push(quote! {
+                if value.is_empty() {
+                    return ::core::result::Result::Err(#kind::Empty);
+                }
+            });
+        }
+        if self.min_len.is_some() || self.max_len.is_some() {
+            checks.push(quote! {
+                let len = #krate::__private::char_count(value);
+            });
+        }
+        if let Some(min) = self.min_len {
+            checks.push(quote! {
+                if len < #min {
+                    return ::core::result::Result::Err(#kind::TooShort { min: #min, actual: len });
+                }
+            });
+        }
+        if let Some(max) = self.max_len {
+            checks.push(quote! {
+                if len > #max {
+                    return ::core::result::Result::Err(#kind::TooLong { max: #max, actual: len });
+                }
+            });
+        }
+        if let Some(ranges) = &self.charset {
+            let ranges = regex::ranges_tokens(ranges);
+            checks.push(quote! {
+                if let ::core::option::Option::Some(ch) = #krate::__private::find_char_outside(value, #ranges) {
+                    return ::core::result::Result::Err(#kind::InvalidChar { ch });
+                }
+            });
+        }
+        if let Some((pattern, program)) = &self.regex {
+            let program = program.to_tokens(&quote! { #krate::__private::regex::Inst });
+            checks.push(quote! {
+                if !#krate::__private::regex::is_match(#program, value) {
+                    return ::core::result::Result::Err(#kind::PatternMismatch { pattern: #pattern });
+                }
+            });
+        }
+        if let Some(range) = &self.range {
+            let lower = range
+                .start
+                .as_ref()
+                .map(|start| quote! { *value >= #start });
+            let upper = range.end.as_ref().map(|end| match range.limits {
+                RangeLimits::HalfOpen(_) => quote! { *value < #end },
+                RangeLimits::Closed(_) => quote! { *value <= #end },
+            });
+            let display = quote!(#range).to_string().replace(' ', "");
+            if let Some(in_range) = lower
+                .into_iter()
+                .chain(upper)
+                .reduce(|lhs, rhs| quote! { #lhs && #rhs })
+            {
+                checks.push(quote! {
+                    if !(#in_range) {
+                        return ::core::result::Result::Err(#kind::OutOfRange { range: #display });
+                    }
+                });
+            }
+        }
+        if let Some(values) = &self.one_of {
+            let is_allowed = values
+                .iter()
+                .map(|value| match value {
+                    Lit::Str(_) => quote! { #krate::__private::str_eq(value, #value) },
+                    _ => quote! { *value == #value },
+                })
+                .reduce(|lhs, rhs| quote! { #lhs || #rhs });
+            let allowed = values.iter().map(|value| match value {
+                Lit::Str(value) => value.value(),
+                value => quote!(#value).to_string(),
+            });
+            checks.push(quote! {
+                if !(#is_allowed) {
+                    return ::core::result::Result::Err(#kind::NotOneOf { allowed: &[#(#allowed),*] });
+                }
+            });
+        }
+        quote! { #(#checks)* }
+    }
+}
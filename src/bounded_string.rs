@@ -0,0 +1,27 @@
+use crate::ValidationError;
+
+/// Fixed-capacity inner types of `StringHolda`, which reject strings that do
+/// not fit instead of allocating.
+pub trait BoundedString: Sized {
+    /// Capacity in bytes.
+    const CAPACITY: usize;
+
+    fn try_from_str(type_name: &'static str, value: &str) -> Result<Self, ValidationError>;
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> BoundedString for heapless::String<N> {
+    const CAPACITY: usize = N;
+
+    fn try_from_str(type_name: &'static str, value: &str) -> Result<Self, ValidationError> {
+        heapless::String::try_from(value).map_err(|()| {
+            ValidationError::new(
+                type_name,
+                crate::ValidationErrorKind::CapacityExceeded {
+                    capacity: N,
+                    actual: value.len(),
+                },
+            )
+        })
+    }
+}
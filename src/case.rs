@@ -0,0 +1,113 @@
+//! Comparisons behind `#[holda(case_insensitive = "...")]`.
+//!
+//! The `eq`, `cmp` and `hash` functions of each mode agree with each other, so
+//! the generated `Eq`, `Ord` and `Hash` impls stay consistent.
+
+use core::cmp::Ordering;
+use core::hash::Hash;
+use core::hash::Hasher;
+
+pub fn eq_ascii(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b)
+}
+
+pub fn cmp_ascii(a: &str, b: &str) -> Ordering {
+    let a = a.bytes().map(|byte| byte.to_ascii_lowercase());
+    let b = b.bytes().map(|byte| byte.to_ascii_lowercase());
+    a.cmp(b)
+}
+
+pub fn hash_ascii<H: Hasher>(value: &str, state: &mut H) {
+    for byte in value.bytes() {
+        state.write_u8(byte.to_ascii_lowercase());
+    }
+    // Same terminator as `str`'s `Hash` impl.
+    state.write_u8(0xff);
+}
+
+pub fn eq_unicode(a: &str, b: &str) -> bool {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.chars().flat_map(char::to_lowercase))
+}
+
+pub fn cmp_unicode(a: &str, b: &str) -> Ordering {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .cmp(b.chars().flat_map(char::to_lowercase))
+}
+
+pub fn hash_unicode<H: Hasher>(value: &str, state: &mut H) {
+    for ch in value.chars().flat_map(char::to_lowercase) {
+        state.write_u32(ch as u32);
+    }
+    state.write_u8(0xff);
+}
+
+/// A `str` that compares and hashes ignoring ASCII case, like
+/// `#[holda(case_insensitive = "ascii")]` types.
+///
+/// With the `equivalent` feature it can look up maps keyed by such types
+/// without allocating a key.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct AsciiCaseInsensitive(str);
+
+impl AsciiCaseInsensitive {
+    pub fn new(value: &str) -> &Self {
+        // SAFETY: `Self` is a `repr(transparent)` wrapper around `str`.
+        unsafe { &*(value as *const str as *const Self) }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for AsciiCaseInsensitive {
+    fn eq(&self, other: &Self) -> bool {
+        eq_ascii(&self.0, &other.0)
+    }
+}
+
+impl Eq for AsciiCaseInsensitive {}
+
+impl Hash for AsciiCaseInsensitive {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_ascii(&self.0, state);
+    }
+}
+
+/// A `str` that compares and hashes ignoring case, like
+/// `#[holda(case_insensitive = "unicode")]` types.
+///
+/// With the `equivalent` feature it can look up maps keyed by such types
+/// without allocating a key.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct UnicodeCaseInsensitive(str);
+
+impl UnicodeCaseInsensitive {
+    pub fn new(value: &str) -> &Self {
+        // SAFETY: `Self` is a `repr(transparent)` wrapper around `str`.
+        unsafe { &*(value as *const str as *const Self) }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for UnicodeCaseInsensitive {
+    fn eq(&self, other: &Self) -> bool {
+        eq_unicode(&self.0, &other.0)
+    }
+}
+
+impl Eq for UnicodeCaseInsensitive {}
+
+impl Hash for UnicodeCaseInsensitive {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_unicode(&self.0, state);
+    }
+}
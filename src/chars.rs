@@ -0,0 +1,79 @@
+//! `const` string helpers shared by the generated validators.
+
+/// Decodes the character starting at byte `at` of valid UTF-8.
+pub(crate) const fn decode_char(bytes: &[u8], at: usize) -> (char, usize) {
+    let first = bytes[at] as u32;
+    let (mut code, width) = if first < 0x80 {
+        (first, 1)
+    } else if first < 0xE0 {
+        (first & 0x1F, 2)
+    } else if first < 0xF0 {
+        (first & 0x0F, 3)
+    } else {
+        (first & 0x07, 4)
+    };
+    let mut i = 1;
+    while i < width {
+        code = (code << 6) | (bytes[at + i] as u32 & 0x3F);
+        i += 1;
+    }
+    match char::from_u32(code) {
+        Some(ch) => (ch, width),
+        None => panic!("invalid UTF-8"),
+    }
+}
+
+pub(crate) const fn in_ranges(ch: char, ranges: &[(char, char)]) -> bool {
+    let mut i = 0;
+    while i < ranges.len() {
+        if ranges[i].0 as u32 <= ch as u32 && ch as u32 <= ranges[i].1 as u32 {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Counts the characters in `value`.
+pub const fn char_count(value: &str) -> usize {
+    let bytes = value.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] & 0xC0 != 0x80 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the first character of `value` not contained in `ranges`.
+pub const fn find_char_outside(value: &str, ranges: &[(char, char)]) -> Option<char> {
+    let bytes = value.as_bytes();
+    let mut at = 0;
+    while at < bytes.len() {
+        let (ch, width) = decode_char(bytes, at);
+        if !in_ranges(ch, ranges) {
+            return Some(ch);
+        }
+        at += width;
+    }
+    None
+}
+
+pub const fn str_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,24 @@
+//! Traits connecting holda types to their inner values, for generated code
+//! that only knows another holda type by name.
+
+/// Implemented by every holda type.
+pub trait Wrapper: Sized {
+    type Inner;
+
+    fn into_inner(self) -> Self::Inner;
+}
+
+/// Implemented by holda types whose constructor is `pub`.
+pub trait Construct: Wrapper {
+    /// `Self`, or `Result<Self, ValidationError>` for validated types.
+    type Output;
+
+    /// Calls `new`, or `try_new` for validated types.
+    fn construct(inner: Self::Inner) -> Self::Output;
+}
+
+/// A layer of a holda type: the type itself, its inner value, and for
+/// `flatten`ed types every layer below. Backs `AsRef` of `flatten`ed types.
+pub trait Layer<T: ?Sized> {
+    fn layer(&self) -> &T;
+}
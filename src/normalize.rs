@@ -0,0 +1,128 @@
+//! Built-in `normalize(...)` steps for StringHolda.
+//!
+//! Each step returns `None` when the value is already normalized so the
+//! generated code only reallocates when something changes.
+
+use crate::chars::decode_char;
+use crate::chars::in_ranges;
+use alloc::string::String;
+use alloc::string::ToString;
+
+pub fn trim(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    (trimmed.len() != value.len()).then(|| trimmed.to_string())
+}
+
+pub fn lowercase(value: &str) -> Option<String> {
+    let lowercase = value.to_lowercase();
+    (lowercase != value).then_some(lowercase)
+}
+
+/// Replaces every run of whitespace with a single space.
+pub fn collapse_whitespace(value: &str) -> Option<String> {
+    if is_collapsed(value) {
+        return None;
+    }
+    let mut collapsed = String::with_capacity(value.len());
+    let mut in_whitespace = false;
+    for ch in value.chars() {
+        if ch.is_whitespace() {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(ch);
+            in_whitespace = false;
+        }
+    }
+    Some(collapsed)
+}
+
+#[cfg(feature = "nfc")]
+pub fn nfc(value: &str) -> Option<String> {
+    use unicode_normalization::UnicodeNormalization;
+
+    if unicode_normalization::is_nfc(value) {
+        None
+    } else {
+        Some(value.nfc().collect())
+    }
+}
+
+pub const fn is_trimmed(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.is_empty() {
+        return true;
+    }
+    let (first, _) = decode_char(bytes, 0);
+    let mut last_at = bytes.len() - 1;
+    while bytes[last_at] & 0xC0 == 0x80 {
+        last_at -= 1;
+    }
+    let (last, _) = decode_char(bytes, last_at);
+    !first.is_whitespace() && !last.is_whitespace()
+}
+
+/// Titlecase letters, which are not uppercase but still change under
+/// `to_lowercase`.
+const TITLECASE: &[(char, char)] = &[
+    ('\u{1C5}', '\u{1C5}'),
+    ('\u{1C8}', '\u{1C8}'),
+    ('\u{1CB}', '\u{1CB}'),
+    ('\u{1F2}', '\u{1F2}'),
+    ('\u{1F88}', '\u{1F8F}'),
+    ('\u{1F98}', '\u{1F9F}'),
+    ('\u{1FA8}', '\u{1FAF}'),
+    ('\u{1FBC}', '\u{1FBC}'),
+    ('\u{1FCC}', '\u{1FCC}'),
+    ('\u{1FFC}', '\u{1FFC}'),
+];
+
+/// Ranges whose uppercase characters have no lowercase mapping, such as
+/// the mathematical and enclosed letters.
+const UNMAPPED_UPPERCASE: &[(char, char)] = &[
+    ('\u{3D2}', '\u{3D4}'),
+    ('\u{2102}', '\u{2125}'),
+    ('\u{2127}', '\u{2129}'),
+    ('\u{212C}', '\u{2131}'),
+    ('\u{2133}', '\u{2145}'),
+    ('\u{1D400}', '\u{1D7CB}'),
+    ('\u{1F130}', '\u{1F189}'),
+];
+
+/// Whether `lowercase` would leave `value` unchanged, i.e. no character
+/// has a lowercase mapping other than itself.
+pub const fn is_lowercase(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut at = 0;
+    while at < bytes.len() {
+        let (ch, width) = decode_char(bytes, at);
+        let mapped = if ch.is_uppercase() {
+            !in_ranges(ch, UNMAPPED_UPPERCASE)
+        } else {
+            in_ranges(ch, TITLECASE)
+        };
+        if mapped {
+            return false;
+        }
+        at += width;
+    }
+    true
+}
+
+pub const fn is_collapsed(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut previous_whitespace = false;
+    let mut at = 0;
+    while at < bytes.len() {
+        let (ch, width) = decode_char(bytes, at);
+        let whitespace = ch.is_whitespace();
+        if whitespace && (ch != ' ' || previous_whitespace) {
+            return false;
+        }
+        previous_whitespace = whitespace;
+        at += width;
+    }
+    true
+}
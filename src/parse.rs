@@ -0,0 +1,62 @@
+use crate::ValidationError;
+use crate::ValidationErrorKind;
+use core::fmt;
+
+/// Why parsing a `#[holda(FromStr)]` type failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind<E> {
+    /// The inner type's `FromStr` rejected the input.
+    Inner(E),
+    /// The parsed value was rejected by the validators.
+    Invalid(ValidationErrorKind),
+}
+
+/// Error returned by the `FromStr` impl of `#[holda(FromStr)]` types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<E> {
+    type_name: &'static str,
+    kind: ParseErrorKind<E>,
+}
+
+impl<E> ParseError<E> {
+    pub const fn new(type_name: &'static str, kind: ParseErrorKind<E>) -> Self {
+        Self { type_name, kind }
+    }
+
+    /// Name of the holda type that failed to parse.
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    pub const fn kind(&self) -> &ParseErrorKind<E> {
+        &self.kind
+    }
+
+    pub fn into_kind(self) -> ParseErrorKind<E> {
+        self.kind
+    }
+}
+
+impl<E> From<ValidationError> for ParseError<E> {
+    fn from(err: ValidationError) -> Self {
+        Self::new(err.type_name(), ParseErrorKind::Invalid(err.kind()))
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Inner(err) => write!(f, "invalid {}: {}", self.type_name, err),
+            ParseErrorKind::Invalid(kind) => write!(f, "invalid {}: {}", self.type_name, kind),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for ParseError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::Inner(err) => Some(err),
+            ParseErrorKind::Invalid(_) => None,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! A tiny Pike VM used by the `regex` validator.
+//!
+//! Patterns are compiled by the derive macro into a slice of [`Inst`] so the
+//! same program can be run both at runtime and during const evaluation of
+//! `holda_lit!`.
+
+use crate::chars::decode_char;
+use crate::chars::in_ranges;
+
+/// Maximum number of instructions in a compiled pattern.
+pub const MAX_INSTS: usize = 512;
+
+const WORDS: usize = MAX_INSTS / 64;
+
+type Stack = [u16; 2 * MAX_INSTS + 1];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Inst {
+    /// Consume one character contained in one of the inclusive ranges.
+    Class(&'static [(char, char)]),
+    /// Continue at both targets.
+    Split(usize, usize),
+    /// Continue at the target.
+    Jmp(usize),
+    /// Assert the start of the input.
+    Start,
+    /// Assert the end of the input.
+    End,
+    Match,
+}
+
+#[derive(Clone, Copy)]
+struct Threads {
+    bits: [u64; WORDS],
+}
+
+impl Threads {
+    const fn new() -> Self {
+        Self { bits: [0; WORDS] }
+    }
+
+    const fn contains(&self, pc: usize) -> bool {
+        self.bits[pc / 64] & (1 << (pc % 64)) != 0
+    }
+
+    const fn insert(&mut self, pc: usize) {
+        self.bits[pc / 64] |= 1 << (pc % 64);
+    }
+
+    /// Adds `pc` and everything reachable from it without consuming input.
+    const fn add(&mut self, stack: &mut Stack, program: &[Inst], pc: usize, at: usize, len: usize) {
+        let mut top = 1;
+        stack[0] = pc as u16;
+        while top > 0 {
+            top -= 1;
+            let pc = stack[top] as usize;
+            if self.contains(pc) {
+                continue;
+            }
+            self.insert(pc);
+            match program[pc] {
+                Inst::Split(a, b) => {
+                    stack[top] = b as u16;
+                    stack[top + 1] = a as u16;
+                    top += 2;
+                }
+                Inst::Jmp(target) => {
+                    stack[top] = target as u16;
+                    top += 1;
+                }
+                Inst::Start => {
+                    if at == 0 {
+                        stack[top] = (pc + 1) as u16;
+                        top += 1;
+                    }
+                }
+                Inst::End => {
+                    if at == len {
+                        stack[top] = (pc + 1) as u16;
+                        top += 1;
+                    }
+                }
+                Inst::Class(_) | Inst::Match => {}
+            }
+        }
+    }
+}
+
+/// Returns whether `program` matches anywhere in `haystack`.
+pub const fn is_match(program: &[Inst], haystack: &str) -> bool {
+    assert!(program.len() <= MAX_INSTS, "regex program is too large");
+    let bytes = haystack.as_bytes();
+    let len = bytes.len();
+    let mut stack: Stack = [0; 2 * MAX_INSTS + 1];
+    let mut current = Threads::new();
+    current.add(&mut stack, program, 0, 0, len);
+    let mut at = 0;
+    loop {
+        let mut pc = 0;
+        while pc < program.len() {
+            if current.contains(pc) && matches!(program[pc], Inst::Match) {
+                return true;
+            }
+            pc += 1;
+        }
+        if at == len {
+            return false;
+        }
+        let (ch, width) = decode_char(bytes, at);
+        let next_at = at + width;
+        let mut next = Threads::new();
+        let mut pc = 0;
+        while pc < program.len() {
+            if current.contains(pc)
+                && let Inst::Class(ranges) = program[pc]
+                && in_ranges(ch, ranges)
+            {
+                next.add(&mut stack, program, pc + 1, next_at, len);
+            }
+            pc += 1;
+        }
+        // Matches may start at any position; `^` rejects the late starts.
+        next.add(&mut stack, program, 0, next_at, len);
+        current = next;
+        at = next_at;
+    }
+}
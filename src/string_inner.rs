@@ -0,0 +1,88 @@
+use alloc::borrow::Cow;
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+
+/// Inner types of `StringHolda` that can be built from both borrowed and
+/// owned strings, taking over the allocation of owned ones where possible.
+pub trait StringInner: Sized {
+    fn from_borrowed(value: &str) -> Self;
+
+    fn from_owned(value: String) -> Self;
+}
+
+impl StringInner for String {
+    fn from_borrowed(value: &str) -> Self {
+        value.to_owned()
+    }
+
+    fn from_owned(value: String) -> Self {
+        value
+    }
+}
+
+impl StringInner for Box<str> {
+    fn from_borrowed(value: &str) -> Self {
+        value.into()
+    }
+
+    fn from_owned(value: String) -> Self {
+        value.into_boxed_str()
+    }
+}
+
+impl StringInner for Rc<str> {
+    fn from_borrowed(value: &str) -> Self {
+        value.into()
+    }
+
+    fn from_owned(value: String) -> Self {
+        value.into()
+    }
+}
+
+impl StringInner for Arc<str> {
+    fn from_borrowed(value: &str) -> Self {
+        value.into()
+    }
+
+    fn from_owned(value: String) -> Self {
+        value.into()
+    }
+}
+
+impl StringInner for Cow<'static, str> {
+    fn from_borrowed(value: &str) -> Self {
+        Cow::Owned(value.to_owned())
+    }
+
+    fn from_owned(value: String) -> Self {
+        Cow::Owned(value)
+    }
+}
+
+/// Strings of up to 23 bytes are stored inline.
+#[cfg(feature = "smol_str")]
+impl StringInner for smol_str::SmolStr {
+    fn from_borrowed(value: &str) -> Self {
+        smol_str::SmolStr::new(value)
+    }
+
+    fn from_owned(value: String) -> Self {
+        value.into()
+    }
+}
+
+/// Strings of up to 24 bytes are stored inline.
+#[cfg(feature = "compact_str")]
+impl StringInner for compact_str::CompactString {
+    fn from_borrowed(value: &str) -> Self {
+        compact_str::CompactString::new(value)
+    }
+
+    fn from_owned(value: String) -> Self {
+        value.into()
+    }
+}
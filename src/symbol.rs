@@ -0,0 +1,223 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::Deref;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::sync::OnceLock;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+use crate::string_inner::StringInner;
+
+/// A string interned in a process-wide table, the inner type of
+/// `#[holda(interned)]` types.
+///
+/// A `Symbol` is a `u32` handle, so copying, comparing and hashing it never
+/// touches the string. `Ord`, `Display`, `Debug` and serde go through the
+/// string. Interned strings are never freed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(u32);
+
+/// Maps strings to their symbols. Only interning takes the write lock.
+static INTERNER: LazyLock<RwLock<HashMap<&'static str, Symbol>>> = LazyLock::new(Default::default);
+
+/// Maps symbols back to their strings without locking.
+static STRINGS: Strings = Strings::new();
+
+/// The size of the first chunk of `Strings`, as a power of two.
+const FIRST_CHUNK_BITS: u32 = 6;
+
+/// Enough chunks for a slot per `u32`.
+const CHUNKS: usize = (u32::BITS - FIRST_CHUNK_BITS + 1) as usize;
+
+/// An append-only arena of interned strings, indexed by `Symbol`. Chunk `k`
+/// holds `2^(FIRST_CHUNK_BITS + k)` slots and is allocated on first use, so
+/// slots never move. Slots are only written under the `INTERNER` write lock,
+/// and each is written once.
+struct Strings {
+    chunks: [OnceLock<Box<[OnceLock<&'static str>]>>; CHUNKS],
+}
+
+impl Strings {
+    const fn new() -> Self {
+        Self {
+            chunks: [const { OnceLock::new() }; CHUNKS],
+        }
+    }
+
+    /// The chunk and offset of slot `index`.
+    fn locate(index: u32) -> (usize, usize) {
+        let shifted = u64::from(index) + (1 << FIRST_CHUNK_BITS);
+        let chunk = shifted.ilog2() - FIRST_CHUNK_BITS;
+        let offset = shifted - (1 << (chunk + FIRST_CHUNK_BITS));
+        (chunk as usize, offset as usize)
+    }
+
+    fn slot(&self, index: u32) -> &OnceLock<&'static str> {
+        let (chunk, offset) = Self::locate(index);
+        let slots = self.chunks[chunk].get_or_init(|| {
+            (0..1usize << (chunk as u32 + FIRST_CHUNK_BITS))
+                .map(|_| OnceLock::new())
+                .collect()
+        });
+        &slots[offset]
+    }
+
+    fn get(&self, index: u32) -> &'static str {
+        let (chunk, offset) = Self::locate(index);
+        self.chunks[chunk]
+            .get()
+            .and_then(|slots| slots[offset].get())
+            .expect("symbols are only created for interned strings")
+    }
+}
+
+impl Symbol {
+    /// The symbol of `value`, interning it if it is new.
+    pub fn intern(value: &str) -> Self {
+        Self::intern_with(value, |value| Box::leak(value.into()))
+    }
+
+    /// Like [`Symbol::intern`], reusing the allocation of `value` if it is
+    /// new.
+    pub fn intern_owned(value: String) -> Self {
+        Self::intern_with(value, |value| Box::leak(value.into_boxed_str()))
+    }
+
+    fn intern_with<S: AsRef<str>>(value: S, leak: impl FnOnce(S) -> &'static str) -> Self {
+        if let Some(symbol) = Self::get(value.as_ref()) {
+            return symbol;
+        }
+        let mut symbols = INTERNER.write().unwrap_or_else(PoisonError::into_inner);
+        // Another thread may have interned it between the two locks.
+        if let Some(symbol) = symbols.get(value.as_ref()) {
+            return *symbol;
+        }
+        let index = u32::try_from(symbols.len()).expect("too many interned strings");
+        let value = leak(value);
+        let symbol = Self(index);
+        // The slot is filled before the symbol escapes the lock.
+        let _ = STRINGS.slot(index).set(value);
+        symbols.insert(value, symbol);
+        symbol
+    }
+
+    /// The symbol of `value` if it is already interned, without interning
+    /// it.
+    pub fn get(value: &str) -> Option<Self> {
+        INTERNER
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(value)
+            .copied()
+    }
+
+    /// The interned string. This does not lock the table.
+    pub fn as_str(self) -> &'static str {
+        STRINGS.get(self.0)
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Symbol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by the strings, so unlike `Eq` it reads the table.
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(value: &str) -> Self {
+        Self::intern(value)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(value: String) -> Self {
+        Self::intern_owned(value)
+    }
+}
+
+impl From<Symbol> for String {
+    fn from(value: Symbol) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl StringInner for Symbol {
+    fn from_borrowed(value: &str) -> Self {
+        Self::intern(value)
+    }
+
+    fn from_owned(value: String) -> Self {
+        Self::intern_owned(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Symbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Symbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SymbolVisitor;
+
+        impl serde::de::Visitor<'_> for SymbolVisitor {
+            type Value = Symbol;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Symbol, E> {
+                Ok(Symbol::intern(value))
+            }
+
+            fn visit_string<E: serde::de::Error>(self, value: String) -> Result<Symbol, E> {
+                Ok(Symbol::intern_owned(value))
+            }
+        }
+
+        deserializer.deserialize_str(SymbolVisitor)
+    }
+}
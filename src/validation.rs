@@ -0,0 +1,109 @@
+use core::convert::Infallible;
+use core::fmt;
+
+/// Why a value was rejected by the validators declared in `#[holda(...)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationErrorKind {
+    Empty,
+    TooShort {
+        min: usize,
+        actual: usize,
+    },
+    TooLong {
+        max: usize,
+        actual: usize,
+    },
+    InvalidChar {
+        ch: char,
+    },
+    PatternMismatch {
+        pattern: &'static str,
+    },
+    OutOfRange {
+        range: &'static str,
+    },
+    NotOneOf {
+        allowed: &'static [&'static str],
+    },
+    /// The value does not fit in a fixed-capacity inner type.
+    CapacityExceeded {
+        capacity: usize,
+        actual: usize,
+    },
+}
+
+impl ValidationErrorKind {
+    /// A short description without the offending details, usable in `const` panics.
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Empty => "value must not be empty",
+            Self::TooShort { .. } => "value is shorter than the minimum length",
+            Self::TooLong { .. } => "value is longer than the maximum length",
+            Self::InvalidChar { .. } => "value contains a character outside the allowed charset",
+            Self::PatternMismatch { .. } => "value does not match the required pattern",
+            Self::OutOfRange { .. } => "value is outside the allowed range",
+            Self::NotOneOf { .. } => "value is not one of the allowed values",
+            Self::CapacityExceeded { .. } => "value does not fit in the inner type's capacity",
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "must not be empty"),
+            Self::TooShort { min, actual } => {
+                write!(f, "must be at least {min} characters long, got {actual}")
+            }
+            Self::TooLong { max, actual } => {
+                write!(f, "must be at most {max} characters long, got {actual}")
+            }
+            Self::InvalidChar { ch } => write!(f, "contains disallowed character {ch:?}"),
+            Self::PatternMismatch { pattern } => write!(f, "must match /{pattern}/"),
+            Self::OutOfRange { range } => write!(f, "must be in {range}"),
+            Self::NotOneOf { allowed } => write!(f, "must be one of {}", allowed.join(", ")),
+            Self::CapacityExceeded { capacity, actual } => {
+                write!(f, "must fit in {capacity} bytes, got {actual}")
+            }
+        }
+    }
+}
+
+/// Error returned when constructing a validated holda type fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationError {
+    type_name: &'static str,
+    kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    pub const fn new(type_name: &'static str, kind: ValidationErrorKind) -> Self {
+        Self { type_name, kind }
+    }
+
+    /// Name of the holda type that rejected the value.
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    pub const fn kind(&self) -> ValidationErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: {}", self.type_name, self.kind)
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// Lets conversions through infallible layers share the error type of
+/// validated ones.
+impl From<Infallible> for ValidationError {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
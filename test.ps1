@@ -1,2 +1,5 @@
 cargo test --all-features
-cargo test --no-default-features
\ No newline at end of file
+cargo test --no-default-features
+cargo test -p holda_no_std
+rustup target add thumbv7em-none-eabihf
+cargo build -p holda_no_std --target thumbv7em-none-eabihf
\ No newline at end of file
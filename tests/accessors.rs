@@ -0,0 +1,49 @@
+use holda::Holda;
+use holda::StringHolda;
+use std::rc::Rc;
+
+#[derive(StringHolda)]
+#[holda(AsMut)]
+struct Note {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+struct Tag {
+    inner: Rc<str>,
+}
+
+#[derive(Holda)]
+#[holda(range = 0..=100)]
+struct Percent {
+    inner: u8,
+}
+
+#[test]
+fn test_inner_from_wrapper() {
+    let note = Note::new("hello");
+    assert_eq!(String::from(note), "hello");
+    let tag = Tag::new("test");
+    let inner: Rc<str> = tag.into();
+    assert_eq!(&*inner, "test");
+    assert_eq!(u8::from(Percent::try_new(42u8).unwrap()), 42);
+}
+
+#[test]
+fn test_accessors() {
+    let mut note = Note::new("hello");
+    assert_eq!(note.inner(), "hello");
+    note.inner_mut().push('!');
+    note.as_mut().push('?');
+    assert_eq!(note.into_inner(), "hello!?");
+
+    let percent = Percent::try_new(7u8).unwrap();
+    assert_eq!(*percent.inner(), 7);
+    assert_eq!(percent.into_inner(), 7);
+}
+
+#[test]
+fn test_mutable_access_is_controlled() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/accessors_*.rs");
+}
@@ -0,0 +1,72 @@
+use holda::Holda;
+use std::collections::HashSet;
+
+#[derive(Holda)]
+#[holda(auto)]
+struct Nothing {
+    inner: (),
+}
+
+#[derive(Holda)]
+#[holda(auto)]
+struct Ratio {
+    inner: f64,
+}
+
+#[derive(Holda)]
+#[holda(auto, range = 0.0..=1.0)]
+struct Probability {
+    inner: f64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Opaque(u8);
+
+#[derive(Holda)]
+#[holda(auto)]
+struct Handle {
+    inner: Opaque,
+}
+
+#[derive(Holda)]
+#[holda(auto)]
+struct Count {
+    inner: i32,
+}
+
+#[test]
+fn test_unsupported_traits_are_skipped() {
+    let nothing = Nothing::new(());
+    assert_eq!(nothing, Nothing::new(()));
+    assert_eq!(format!("{nothing:?}"), "()");
+
+    let handle = Handle::new(Opaque(1));
+    assert_eq!(handle, Handle::new(Opaque(1)));
+    assert_eq!(format!("{handle:?}"), "Opaque(1)");
+}
+
+#[test]
+fn test_supported_traits_are_kept() {
+    let ratio = Ratio::new(0.5);
+    assert_eq!(ratio.to_string(), "0.5");
+    assert!(ratio < Ratio::new(0.75));
+    assert_eq!(ratio.clone(), ratio);
+
+    let counts: HashSet<Count> = [Count::new(1), Count::new(1), Count::new(2)].into();
+    assert_eq!(counts.len(), 2);
+    assert_eq!(Count::new(3).max(Count::new(4)), Count::new(4));
+}
+
+#[test]
+fn test_clone_helpers() {
+    let mut probability = Probability::try_new(0.5).unwrap();
+    assert!(probability.try_modify(|value| *value = 2.0).is_err());
+    probability.try_modify(|value| *value = 0.25).unwrap();
+    assert_eq!(*probability, 0.25);
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/auto_missing_trait.rs");
+}
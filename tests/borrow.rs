@@ -0,0 +1,69 @@
+use holda::Holda;
+use holda::StringHolda;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(StringHolda)]
+#[holda(Borrow)]
+struct UserName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(Borrow)]
+struct Tag {
+    inner: Arc<str>,
+}
+
+#[derive(Holda)]
+#[holda(Borrow)]
+struct UserId {
+    inner: Uuid,
+}
+
+#[test]
+fn test_hash_map_lookup_by_str() {
+    let mut ages = HashMap::new();
+    ages.insert(UserName::new("bob"), 42);
+    assert_eq!(ages.get("bob"), Some(&42));
+    assert_eq!(ages.get(&String::from("bob")), Some(&42));
+    assert_eq!(ages.get("alice"), None);
+}
+
+#[test]
+fn test_btree_map_lookup_by_str() {
+    let mut ages = BTreeMap::new();
+    ages.insert(UserName::new("bob"), 42);
+    ages.insert(UserName::new("alice"), 7);
+    assert_eq!(ages.get("alice"), Some(&7));
+    assert_eq!(
+        ages.range::<str, _>((std::ops::Bound::Included("b"), std::ops::Bound::Unbounded))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_shared_str_lookup() {
+    let tags: HashSet<Tag> = ["red", "green"].into_iter().map(Tag::from).collect();
+    assert!(tags.contains("red"));
+    assert!(tags.contains(&Arc::<str>::from("green")));
+    assert!(!tags.contains("blue"));
+}
+
+#[test]
+fn test_lookup_by_inner() {
+    let id = Uuid::new_v4();
+    let mut names = HashMap::new();
+    names.insert(UserId::new(id), "bob");
+    assert_eq!(names.get(&id), Some(&"bob"));
+}
+
+#[test]
+fn test_borrow_rejected_for_case_insensitive() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/borrow_*.rs");
+}
@@ -0,0 +1,84 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(borrowed = UserNameRef)]
+pub struct UserName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(borrowed = TagRef, non_empty, max_len = 8)]
+pub struct Tag {
+    inner: Rc<str>,
+}
+
+#[derive(StringHolda)]
+#[holda(borrowed = EmailRef, normalize(trim, lowercase))]
+pub struct Email {
+    inner: Box<str>,
+}
+
+fn greet(name: &UserNameRef) -> String {
+    format!("hello {name}")
+}
+
+#[test]
+fn test_borrowed() {
+    let name = UserName::new("alice");
+    let name_ref: &UserNameRef = &name;
+    assert_eq!(name_ref.as_str(), "alice");
+    assert_eq!(name_ref.len(), 5);
+    assert_eq!(*name_ref, *"alice");
+    assert_eq!(greet(&name), "hello alice");
+    assert_eq!(greet(UserNameRef::new("bob")), "hello bob");
+    assert_eq!(format!("{name_ref:?}"), "\"alice\"");
+
+    let owned: UserName = name_ref.to_owned();
+    assert_eq!(owned, name);
+    assert_eq!(
+        UserName::from(UserNameRef::new("bob")),
+        UserName::new("bob")
+    );
+
+    let borrowed: &UserNameRef = name.borrow();
+    assert_eq!(borrowed, UserNameRef::new("alice"));
+    assert!(UserNameRef::new("alice") < UserNameRef::new("bob"));
+}
+
+#[test]
+fn test_borrowed_lookup() {
+    let mut ages = HashMap::new();
+    ages.insert(UserName::new("alice"), 30);
+    assert_eq!(ages.get(UserNameRef::new("alice")), Some(&30));
+    assert_eq!(ages.get(UserNameRef::new("bob")), None);
+}
+
+#[test]
+fn test_borrowed_validated() {
+    const RUST: &TagRef = match TagRef::try_new("rust") {
+        Ok(tag) => tag,
+        Err(_) => panic!(),
+    };
+    assert_eq!(RUST.to_owned(), Tag::try_new("rust").unwrap());
+    assert!(TagRef::try_new("").is_err());
+    let err = TagRef::try_new("far too long").unwrap_err();
+    assert_eq!(err.type_name(), "Tag");
+}
+
+#[test]
+fn test_borrowed_normalized() {
+    let email = Email::new("  Alice@Example.com ");
+    let email_ref: &EmailRef = &email;
+    assert_eq!(email_ref.as_str(), "alice@example.com");
+    assert_eq!(email_ref.to_owned(), email);
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/borrowed_*.rs");
+}
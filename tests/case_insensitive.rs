@@ -0,0 +1,71 @@
+use holda::StringHolda;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+#[derive(StringHolda)]
+#[holda(case_insensitive = "ascii")]
+struct HeaderName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(case_insensitive = "unicode")]
+struct UserName {
+    inner: String,
+}
+
+#[test]
+fn test_ascii_eq() {
+    assert_eq!(
+        HeaderName::new("Content-Type"),
+        HeaderName::new("content-type")
+    );
+    assert_ne!(
+        HeaderName::new("Content-Type"),
+        HeaderName::new("Content-Length")
+    );
+    assert!(&HeaderName::new("Content-Type") == "CONTENT-TYPE");
+    // Only ASCII letters are folded.
+    assert_ne!(HeaderName::new("É"), HeaderName::new("é"));
+}
+
+#[test]
+fn test_unicode_eq() {
+    assert_eq!(UserName::new("ÉLODIE"), UserName::new("élodie"));
+    assert!(&UserName::new("Straße") == "STRAßE");
+}
+
+#[test]
+fn test_ord() {
+    assert_eq!(
+        HeaderName::new("accept").cmp(&HeaderName::new("Content-Type")),
+        Ordering::Less
+    );
+    assert_eq!(
+        HeaderName::new("ACCEPT").cmp(&HeaderName::new("accept")),
+        Ordering::Equal
+    );
+    assert_eq!(
+        UserName::new("Émile").partial_cmp(&UserName::new("émile")),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn test_hash_matches_eq() {
+    let mut headers = HashSet::new();
+    headers.insert(HeaderName::new("Content-Type"));
+    assert!(headers.contains(&HeaderName::new("CONTENT-TYPE")));
+    assert!(!headers.insert(HeaderName::new("content-type")));
+
+    let mut users = HashSet::new();
+    users.insert(UserName::new("ÉLODIE"));
+    assert!(users.contains(&UserName::new("élodie")));
+}
+
+#[test]
+fn test_display_preserves_spelling() {
+    let header = HeaderName::new("Content-Type");
+    assert_eq!(header.to_string(), "Content-Type");
+    assert_eq!(format!("{header:?}"), "\"Content-Type\"");
+}
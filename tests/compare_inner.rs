@@ -0,0 +1,92 @@
+use holda::Holda;
+use holda::StringHolda;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::rc::Rc;
+use uuid::Uuid;
+
+#[derive(StringHolda)]
+#[holda(CompareInner)]
+struct UserName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(CompareInner, case_insensitive = "ascii")]
+struct HeaderName {
+    inner: Rc<str>,
+}
+
+#[derive(StringHolda)]
+#[holda(CompareInner)]
+struct Label {
+    inner: Cow<'static, str>,
+}
+
+#[derive(Holda)]
+#[holda(CompareInner)]
+struct UserId {
+    inner: Uuid,
+}
+
+#[derive(Holda)]
+#[holda(CompareInner)]
+struct Score {
+    inner: u32,
+}
+
+#[test]
+fn test_string_family() {
+    let user_name = UserName::new("bob");
+    assert!(user_name == "bob");
+    assert!("bob" == user_name);
+    assert!(user_name == *"bob");
+    assert!(*"bob" == user_name);
+    let owned = String::from("bob");
+    assert!(user_name == owned);
+    assert!(owned == user_name);
+    assert!(user_name == Cow::Borrowed("bob"));
+    assert!(Cow::<str>::Owned("bob".to_string()) == user_name);
+    assert!(user_name != "alice");
+}
+
+#[test]
+fn test_string_ordering() {
+    let user_name = UserName::new("bob");
+    assert_eq!(user_name.partial_cmp("carol"), Some(Ordering::Less));
+    assert!("alice" < user_name);
+    let owned = String::from("bobby");
+    assert!(owned > user_name);
+}
+
+#[test]
+fn test_cow_inner() {
+    let label = Label::new(Cow::Borrowed("urgent"));
+    assert!(label == "urgent");
+    assert!(label == Cow::Borrowed("urgent"));
+    assert!(Cow::<str>::Owned("urgent".to_string()) == label);
+    assert!("low" < label);
+}
+
+#[test]
+fn test_case_insensitive() {
+    let header = HeaderName::new("Content-Type");
+    assert!(header == "content-type");
+    assert!("CONTENT-TYPE" == header);
+    let inner: Rc<str> = Rc::from("CONTENT-type");
+    assert!(header == inner);
+    assert_eq!(header.partial_cmp("content-type"), Some(Ordering::Equal));
+}
+
+#[test]
+fn test_inner_type() {
+    let uuid = Uuid::new_v4();
+    let user_id = UserId::new(uuid);
+    assert!(user_id == uuid);
+    assert!(uuid == user_id);
+    assert_eq!(user_id.partial_cmp(&uuid), Some(Ordering::Equal));
+
+    let score = Score::new(15u32);
+    assert!(score == 15);
+    assert!(20 > score);
+}
@@ -0,0 +1,89 @@
+use holda::Holda;
+use holda::StringHolda;
+use uuid::Uuid;
+
+#[derive(StringHolda)]
+#[holda(normalize(trim))]
+struct RawEmail {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(try_from(RawEmail), regex = "^[^@ ]+@[^@ ]+$")]
+struct VerifiedEmail {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(into(AdminUserId))]
+struct UserId {
+    inner: Uuid,
+}
+
+#[derive(Holda)]
+#[holda(from(GuestId))]
+struct AdminUserId {
+    inner: Uuid,
+}
+
+#[derive(Holda)]
+struct GuestId {
+    inner: Uuid,
+}
+
+#[derive(Holda)]
+#[holda(NoEq, NoOrd, NoHash)]
+struct Celsius {
+    inner: f64,
+}
+
+#[derive(Holda)]
+#[holda(NoEq, NoOrd, NoHash, from(Celsius, with = celsius_to_kelvin))]
+struct Kelvin {
+    inner: f64,
+}
+
+#[derive(Holda)]
+#[holda(try_from(Kelvin, with = kelvin_to_millis), range = 0..=1_000_000)]
+struct MilliKelvin {
+    inner: u32,
+}
+
+fn celsius_to_kelvin(celsius: f64) -> f64 {
+    celsius + 273.15
+}
+
+fn kelvin_to_millis(kelvin: f64) -> u32 {
+    (kelvin * 1000.0) as u32
+}
+
+#[test]
+fn test_try_from_validates() {
+    let verified = VerifiedEmail::try_from(RawEmail::new(" bob@example.com ")).unwrap();
+    assert_eq!(*verified, "bob@example.com");
+    assert!(VerifiedEmail::try_from(RawEmail::new("bob")).is_err());
+}
+
+#[test]
+fn test_from_and_into() {
+    let id = Uuid::new_v4();
+    let admin: AdminUserId = UserId::new(id).into();
+    assert_eq!(*admin, id);
+    let admin = AdminUserId::from(GuestId::new(id));
+    assert_eq!(*admin, id);
+}
+
+#[test]
+fn test_mapping_function() {
+    let kelvin = Kelvin::from(Celsius::new(0.0));
+    assert_eq!(*kelvin, 273.15);
+    let millis = MilliKelvin::try_from(kelvin).unwrap();
+    assert_eq!(*millis, 273_150);
+    assert!(MilliKelvin::try_from(Kelvin::new(2000.0)).is_err());
+}
+
+#[test]
+fn test_mismatched_conversions_fail_to_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/conversions_*.rs");
+}
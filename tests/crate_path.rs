@@ -0,0 +1,80 @@
+mod platform {
+    pub use holda;
+}
+
+mod product {
+    // Shadows the `holda` crate, so any path not routed through the facade
+    // fails to resolve.
+    #[allow(dead_code)]
+    mod holda {}
+
+    use crate::platform::holda::Holda;
+    use crate::platform::holda::StringHolda;
+
+    #[derive(StringHolda)]
+    #[holda(
+        crate = "crate::platform::holda",
+        normalize(trim),
+        case_insensitive = "ascii",
+        regex = "^[a-z]+$",
+        max_len = 8
+    )]
+    pub struct UserName {
+        inner: String,
+    }
+
+    #[derive(StringHolda)]
+    #[holda(crate = "crate::platform::holda", try_from(Nickname), non_empty)]
+    pub struct DisplayName {
+        inner: String,
+    }
+
+    #[derive(StringHolda)]
+    #[holda(crate = "crate::platform::holda")]
+    pub struct Nickname {
+        inner: String,
+    }
+
+    #[derive(Holda)]
+    #[holda(crate = "crate::platform::holda", FromStr, range = 1..=100)]
+    pub struct Percent {
+        inner: u8,
+    }
+
+    #[derive(Holda)]
+    #[holda(crate = "crate::platform::holda", flatten)]
+    pub struct AdminName {
+        inner: UserName,
+    }
+}
+
+use product::AdminName;
+use product::DisplayName;
+use product::Nickname;
+use product::Percent;
+use product::UserName;
+
+#[test]
+fn test_crate_path() {
+    let name = UserName::try_new(" alice ").unwrap();
+    assert!(name == *"ALICE");
+    assert!(UserName::try_new("alice1").is_err());
+    #[cfg(feature = "std")]
+    assert!("alice".parse::<UserName>().is_ok());
+
+    let percent: Percent = "42".parse().unwrap();
+    assert_eq!(*percent, 42);
+    assert!("0".parse::<Percent>().is_err());
+
+    let admin = AdminName::new(UserName::try_new(" bob ").unwrap());
+    assert_eq!(AsRef::<str>::as_ref(&admin), "bob");
+
+    assert!(DisplayName::try_from(Nickname::new("")).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_crate_path_serde() {
+    let name: UserName = serde_json::from_str("\"alice\"").unwrap();
+    assert_eq!(serde_json::to_string(&name).unwrap(), "\"alice\"");
+}
@@ -0,0 +1,44 @@
+use holda::ValidationErrorKind;
+use std::rc::Rc;
+use uuid::Uuid;
+
+holda::define! {
+    /// Login name.
+    pub UserName: String [non_empty, max_len = 8];
+    pub OrgId: Uuid;
+    #[holda(NoDisplay)]
+    pub(crate) Tag: Rc<str> [normalize(trim, lowercase)];
+    Ratio: f64 [NoEq, NoOrd, NoHash, range = 0.0..=1.0];
+    Count: u32
+}
+
+#[test]
+fn test_define() {
+    let name = UserName::try_new("alice").unwrap();
+    assert_eq!(*name, "alice");
+    assert_eq!(name.to_string(), "alice");
+    assert_eq!(
+        UserName::try_new("").unwrap_err().kind(),
+        ValidationErrorKind::Empty
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        "bob".parse::<UserName>().unwrap(),
+        UserName::try_from("bob").unwrap()
+    );
+
+    let uuid = Uuid::new_v4();
+    assert_eq!(*OrgId::from(uuid), uuid);
+
+    let tag = Tag::from(" Red ");
+    assert_eq!(&**tag, "red");
+
+    assert!(Ratio::try_new(1.5).is_err());
+    assert_eq!(*Count::new(3u32), 3);
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/define_*.rs");
+}
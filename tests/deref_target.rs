@@ -0,0 +1,88 @@
+use holda::Holda;
+use holda::StringHolda;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(StringHolda)]
+#[holda(deref = str)]
+struct UserName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(deref = str)]
+struct Tag {
+    inner: Rc<str>,
+}
+
+#[derive(StringHolda)]
+#[holda(deref = str)]
+struct Label {
+    inner: Arc<str>,
+}
+
+#[derive(StringHolda)]
+#[holda(deref = str)]
+struct Code {
+    inner: Box<str>,
+}
+
+#[derive(Holda)]
+#[holda(deref = [u8], NoDisplay)]
+struct Payload {
+    inner: Vec<u8>,
+}
+
+#[derive(Holda)]
+#[holda(deref = Path, NoDisplay)]
+struct ConfigPath {
+    inner: PathBuf,
+}
+
+fn takes_str(value: &str) -> usize {
+    value.len()
+}
+
+#[test]
+fn test_string_derefs_to_str() {
+    let mut user_name = UserName::new("bob");
+    assert_eq!(&*user_name, "bob");
+    assert_eq!(user_name.len(), 3);
+    assert_eq!(takes_str(&user_name), 3);
+    user_name.make_ascii_uppercase();
+    assert_eq!(AsRef::<str>::as_ref(&user_name), "BOB");
+    assert_eq!(AsRef::<String>::as_ref(&user_name), "BOB");
+}
+
+#[test]
+fn test_shared_strs_deref_to_str() {
+    let tag = Tag::new("test");
+    assert_eq!(&*tag, "test");
+    assert!(tag.starts_with("te"));
+    let label = Label::new("test");
+    assert_eq!(takes_str(&label), 4);
+    let code = Code::new("abc");
+    assert_eq!(code.to_uppercase(), "ABC");
+}
+
+#[test]
+fn test_vec_derefs_to_slice() {
+    let mut payload = Payload::new(vec![1, 2, 3]);
+    assert_eq!(&*payload, &[1, 2, 3][..]);
+    payload[0] = 9;
+    assert_eq!(payload.first(), Some(&9));
+    assert_eq!(AsRef::<[u8]>::as_ref(&payload), &[9, 2, 3]);
+}
+
+#[test]
+fn test_path_buf_derefs_to_path() {
+    let path = ConfigPath::new(PathBuf::from("/etc/app/config.toml"));
+    assert_eq!(path.extension().unwrap(), "toml");
+    assert_eq!(
+        AsRef::<Path>::as_ref(&path),
+        Path::new("/etc/app/config.toml")
+    );
+    assert!(path.is_absolute());
+}
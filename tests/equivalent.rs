@@ -0,0 +1,39 @@
+#![cfg(feature = "equivalent")]
+
+use hashbrown::HashMap;
+use holda::AsciiCaseInsensitive;
+use holda::StringHolda;
+use holda::UnicodeCaseInsensitive;
+
+#[derive(StringHolda)]
+#[holda(case_insensitive = "ascii")]
+struct HeaderName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(case_insensitive = "unicode")]
+struct City {
+    inner: String,
+}
+
+#[test]
+fn test_ascii_lookup() {
+    let mut headers = HashMap::new();
+    headers.insert(HeaderName::new("Content-Type"), "text/plain");
+    assert_eq!(
+        headers.get(AsciiCaseInsensitive::new("content-type")),
+        Some(&"text/plain")
+    );
+    assert_eq!(headers.get(AsciiCaseInsensitive::new("accept")), None);
+}
+
+#[test]
+fn test_unicode_lookup() {
+    let mut population = HashMap::new();
+    population.insert(City::new("Zürich"), 421_878);
+    assert_eq!(
+        population.get(UnicodeCaseInsensitive::new("ZÜRICH")),
+        Some(&421_878)
+    );
+}
@@ -0,0 +1,98 @@
+use holda::Holda;
+use holda::StringHolda;
+use holda::holda_lit;
+
+#[derive(StringHolda)]
+struct UserName {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(flatten)]
+struct AdminName {
+    inner: UserName,
+}
+
+#[derive(Holda)]
+#[holda(flatten, NoDisplay)]
+struct SuperAdminName {
+    inner: AdminName,
+}
+
+#[derive(StringHolda)]
+#[holda(non_empty)]
+struct Email {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(flatten)]
+struct WorkEmail {
+    inner: Email,
+}
+
+#[derive(Holda)]
+#[holda(range = 0..=1_000_000)]
+struct Limit {
+    inner: u32,
+}
+
+const MIN_QUOTA: Limit = holda_lit!(Limit, 1);
+const MAX_QUOTA: Limit = holda_lit!(Limit, 1000);
+
+#[derive(Holda)]
+#[holda(flatten, range = MIN_QUOTA..=MAX_QUOTA)]
+struct Quota {
+    inner: Limit,
+}
+
+#[test]
+fn test_from_innermost() {
+    let admin = AdminName::from("root");
+    assert_eq!(**admin, "root");
+    let admin = AdminName::from(String::from("root"));
+    assert_eq!(admin.to_string(), "root");
+    let admin = AdminName::from(UserName::new("root"));
+    assert_eq!(admin.len(), 4);
+    let super_admin = SuperAdminName::from("root");
+    assert_eq!(super_admin.len(), 4);
+}
+
+#[test]
+fn test_as_ref_every_layer() {
+    let super_admin = SuperAdminName::from("root");
+    let admin: &AdminName = super_admin.as_ref();
+    let user_name: &UserName = super_admin.as_ref();
+    let inner: &String = super_admin.as_ref();
+    let borrowed: &str = super_admin.as_ref();
+    assert_eq!(admin.to_string(), "root");
+    assert_eq!(user_name.to_string(), "root");
+    assert_eq!(inner, "root");
+    assert_eq!(borrowed, "root");
+}
+
+#[test]
+fn test_validated_layers() {
+    let email = WorkEmail::from(Email::try_new("bob@example.com").unwrap());
+    assert_eq!(email.to_string(), "bob@example.com");
+
+    let quota = Quota::try_new(10u32).unwrap();
+    assert_eq!(**quota, 10);
+    assert_eq!(Quota::try_new(0u32).unwrap_err().type_name(), "Quota");
+    assert_eq!(
+        Quota::try_new(2_000_000u32).unwrap_err().type_name(),
+        "Limit"
+    );
+    let limit = Limit::try_new(5000u32).unwrap();
+    assert!(Quota::try_from(limit).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_through_layers() {
+    let admin: SuperAdminName = serde_json::from_str("\"root\"").unwrap();
+    assert_eq!(admin.len(), 4);
+    assert_eq!(serde_json::to_string(&admin).unwrap(), "\"root\"");
+    let quota: Result<Quota, _> = serde_json::from_str("0");
+    assert!(quota.is_err());
+}
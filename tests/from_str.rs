@@ -0,0 +1,104 @@
+use holda::Holda;
+use holda::ParseErrorKind;
+use holda::StringHolda;
+use holda::ValidationError;
+use holda::ValidationErrorKind;
+use std::error::Error;
+use std::net::IpAddr;
+use uuid::Uuid;
+
+fn round_down(value: u64) -> u64 {
+    value / 10 * 10
+}
+
+#[derive(Holda)]
+#[holda(FromStr)]
+struct UserId {
+    inner: Uuid,
+}
+
+#[derive(Holda)]
+#[holda(FromStr, range = 1..=65535)]
+struct Port {
+    inner: u16,
+}
+
+#[derive(Holda)]
+#[holda(FromStr, normalize = round_down)]
+struct Bucket {
+    inner: u64,
+}
+
+#[derive(Holda)]
+#[holda(FromStr)]
+struct Host {
+    inner: IpAddr,
+}
+
+#[derive(StringHolda)]
+#[holda(from_str_error = "validation")]
+struct UserName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(non_empty, from_str_error = "validation")]
+struct Code {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(FromStr, flatten)]
+struct AdminName {
+    inner: UserName,
+}
+
+#[test]
+fn test_parse() {
+    let id = Uuid::new_v4();
+    let user_id: UserId = id.to_string().parse().unwrap();
+    assert_eq!(*user_id, id);
+    let host: Host = "127.0.0.1".parse().unwrap();
+    assert!(host.is_loopback());
+    let admin: AdminName = "root".parse().unwrap();
+    assert_eq!(admin.to_string(), "root");
+}
+
+#[test]
+fn test_inner_error_is_wrapped() {
+    let err = "not-a-port".parse::<Port>().unwrap_err();
+    assert_eq!(err.type_name(), "Port");
+    assert!(matches!(err.kind(), ParseErrorKind::Inner(_)));
+    assert!(err.source().is_some());
+    assert_eq!(
+        err.to_string(),
+        "invalid Port: invalid digit found in string"
+    );
+}
+
+#[test]
+fn test_validation_applies() {
+    assert_eq!(*"8080".parse::<Port>().unwrap(), 8080);
+    let err = "0".parse::<Port>().unwrap_err();
+    assert_eq!(err.type_name(), "Port");
+    assert!(matches!(err.kind(), ParseErrorKind::Invalid(_)));
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_normalization_applies() {
+    assert_eq!(*"47".parse::<Bucket>().unwrap(), 40);
+}
+
+#[test]
+fn test_validation_error() {
+    assert_eq!(*"abc".parse::<Code>().unwrap(), "abc");
+    let err: ValidationError = "".parse::<Code>().unwrap_err();
+    assert_eq!(err.kind(), ValidationErrorKind::Empty);
+}
+
+#[test]
+fn test_string_holda_rejects_option() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/from_str_*.rs");
+}
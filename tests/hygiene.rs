@@ -0,0 +1,155 @@
+//! Derives in a module without the prelude and with common names shadowed,
+//! so any generated path that is not absolute fails to compile.
+
+#[no_implicit_prelude]
+#[allow(dead_code)]
+mod shadowed {
+    use ::holda::Holda;
+    use ::holda::StringHolda;
+
+    mod std {}
+    mod core {}
+    mod holda {}
+    mod serde {}
+    mod eyre {}
+
+    type Result<T> = ::core::result::Result<T, ()>;
+    type Option = ();
+    struct Ok;
+    struct Err;
+    struct Some;
+    struct None;
+    struct String;
+    struct Box;
+    trait Clone {}
+    trait PartialEq {}
+    trait PartialOrd {}
+    trait Eq {}
+    trait Ord {}
+    trait Into {}
+    trait From {}
+    trait TryFrom {}
+    trait AsRef {}
+    trait Extend {}
+    trait Sized {}
+
+    fn strip_dashes(value: ::std::string::String) -> ::std::string::String {
+        ::std::string::ToString::to_string(&value.replace('-', ""))
+    }
+
+    #[derive(StringHolda)]
+    #[holda(
+        normalize(trim, lowercase),
+        normalize = strip_dashes,
+        case_insensitive = "ascii",
+        CompareInner,
+        regex = "^[a-z]+$",
+        min_len = 2,
+        max_len = 16,
+        from_str_error = "validation"
+    )]
+    pub struct UserName {
+        inner: ::std::string::String,
+    }
+
+    #[derive(StringHolda)]
+    #[holda(Borrow, AsMut, new_unchecked, ref_cast)]
+    #[repr(transparent)]
+    pub struct Note {
+        inner: ::std::string::String,
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(StringHolda)]
+    #[holda(interned, normalize(lowercase), max_len = 8)]
+    pub struct Keyword {
+        inner: ::holda::Symbol,
+    }
+
+    #[derive(StringHolda)]
+    #[holda(borrowed = TopicRef, non_empty)]
+    pub struct Topic {
+        inner: ::std::sync::Arc<str>,
+    }
+
+    #[derive(StringHolda)]
+    #[holda(borrowed = SlugRef, normalize(lowercase))]
+    pub struct Slug {
+        inner: ::std::string::String,
+    }
+
+    #[derive(StringHolda)]
+    #[holda(non_empty, charset = "a-z", one_of("red", "green"))]
+    pub struct Color {
+        inner: ::std::rc::Rc<str>,
+    }
+
+    fn to_cow(value: ::std::string::String) -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Owned(value)
+    }
+
+    #[derive(StringHolda)]
+    #[holda(try_from(Note, with = to_cow), non_empty)]
+    pub struct Label {
+        inner: ::std::borrow::Cow<'static, str>,
+    }
+
+    #[derive(Holda)]
+    #[holda(FromStr, range = 1..=100, auto, ref_cast)]
+    #[repr(transparent)]
+    pub struct Percent {
+        inner: u8,
+    }
+
+    #[derive(Holda)]
+    #[holda(flatten)]
+    pub struct AdminName {
+        inner: UserName,
+    }
+
+    #[derive(Holda)]
+    #[holda(into(Level))]
+    pub struct Rank {
+        inner: u8,
+    }
+
+    #[derive(Holda)]
+    #[holda(preset = "secret")]
+    pub struct Token {
+        inner: u64,
+    }
+
+    #[derive(Holda)]
+    pub struct Level {
+        inner: u8,
+    }
+
+    pub fn red() -> Color {
+        ::holda::holda_lit!(Color, "red")
+    }
+}
+
+use shadowed::*;
+use std::fmt::Write;
+
+#[test]
+fn test_shadowed_names() {
+    let name = UserName::try_new(" Ali-ce ").unwrap();
+    assert_eq!(name, *"alice");
+    assert!(UserName::try_new("a").is_err());
+    assert_eq!("bob".parse::<UserName>().unwrap(), *"BOB");
+
+    let mut note = Note::from('a');
+    write!(note, "bc").unwrap();
+    note.extend(["d"]);
+    assert_eq!(note.as_mut(), "abcd");
+
+    assert_eq!(Color::try_from("red").unwrap(), red());
+    assert!(Color::try_from(String::from("blue")).is_err());
+    assert!(Label::try_from(Note::new("")).is_err());
+
+    assert_eq!("42".parse::<Percent>().unwrap().to_string(), "42");
+    assert_eq!(AsRef::<str>::as_ref(&AdminName::new(name)), "alice");
+    assert_eq!(*Level::from(Rank::new(3)), 3);
+    assert_eq!(format!("{:?}", Token::new(7u64)), "Token(<redacted>)");
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "std")]
+
+use std::collections::HashSet;
+use std::thread;
+
+use holda::StringHolda;
+use holda::Symbol;
+
+#[derive(StringHolda)]
+#[holda(interned)]
+pub struct Ident {
+    inner: Symbol,
+}
+
+#[derive(StringHolda)]
+#[holda(interned, normalize(lowercase), regex = "^[a-z_]+$")]
+pub struct Keyword {
+    inner: Symbol,
+}
+
+holda::define! {
+    pub Label: Symbol [interned];
+}
+
+// `Copy` is implied by `interned`, and accepted anyway.
+#[holda::newtype(Copy, interned)]
+pub struct Field {
+    inner: Symbol,
+}
+
+#[test]
+fn test_interned() {
+    let a = Ident::new("foo");
+    let b: Ident = "foo".parse().unwrap();
+    let c = a;
+    assert_eq!(a, b);
+    assert_eq!(a, c);
+    assert_ne!(a, Ident::new("bar"));
+    assert_eq!(a.into_inner(), Symbol::intern("foo"));
+
+    assert_eq!(&*a, "foo");
+    assert_eq!(a.len(), 3);
+    assert_eq!(a.to_string(), "foo");
+    assert_eq!(format!("{a:?}"), "\"foo\"");
+    assert!(Ident::new("apple") < Ident::new("banana"));
+
+    let set: HashSet<Ident> = ["x", "y", "x"].into_iter().map(Ident::from).collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_interned_define_and_newtype() {
+    let label: Label = "name".parse().unwrap();
+    let field = Field::new("name");
+    let copy = field;
+    assert_eq!(&*label, &*field);
+    assert_eq!(&*copy, &*field);
+    assert_eq!(std::mem::size_of::<Field>(), 4);
+}
+
+#[test]
+fn test_interned_validated() {
+    let kw = Keyword::try_new("LET").unwrap();
+    assert_eq!(&*kw, "let");
+    assert_eq!(kw, Keyword::try_from(String::from("let")).unwrap());
+    assert!(Keyword::try_new("l3t").is_err());
+}
+
+#[test]
+fn test_only_accepted_values_are_interned() {
+    assert!(Keyword::try_new("rejected_9").is_err());
+    assert_eq!(Symbol::get("rejected_9"), None);
+    assert!("parsed_9".parse::<Keyword>().is_err());
+    assert_eq!(Symbol::get("parsed_9"), None);
+
+    let kw = Keyword::try_from("MATCH").unwrap();
+    assert_eq!(Symbol::get("match"), Some(kw.into_inner()));
+    assert_eq!(Symbol::get("MATCH"), None);
+}
+
+#[test]
+fn test_interned_across_threads() {
+    let symbols: Vec<Symbol> = (0..8)
+        .map(|_| thread::spawn(|| Symbol::intern("shared")))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    assert!(symbols.iter().all(|symbol| *symbol == symbols[0]));
+    assert_eq!(symbols[0].as_str(), "shared");
+}
+
+#[test]
+fn test_interned_while_reading() {
+    let handles: Vec<_> = (0..4)
+        .map(|thread| {
+            thread::spawn(move || {
+                let symbols: Vec<Symbol> = (0..500)
+                    .map(|i| Symbol::intern_owned(format!("many_{thread}_{i}")))
+                    .collect();
+                for (i, symbol) in symbols.iter().enumerate() {
+                    assert_eq!(symbol.as_str(), format!("many_{thread}_{i}"));
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_interned_serde() {
+    let ident = Ident::new("serde");
+    let json = serde_json::to_string(&ident).unwrap();
+    assert_eq!(json, "\"serde\"");
+    let back: Ident = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, ident);
+
+    assert!(serde_json::from_str::<Keyword>("\"serde_9\"").is_err());
+    assert_eq!(Symbol::get("serde_9"), None);
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/interned_*.rs");
+}
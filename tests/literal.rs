@@ -0,0 +1,41 @@
+use holda::Holda;
+use holda::StringHolda;
+use holda::holda_lit;
+
+#[derive(StringHolda)]
+#[holda(non_empty, max_len = 16, regex = "^[a-z]+$")]
+struct UserName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+struct Tag {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(range = 1..=1024)]
+struct Port {
+    inner: u16,
+}
+
+const HTTP: Port = holda_lit!(Port, 80);
+
+#[test]
+fn test_string_literal() {
+    let admin = holda_lit!(UserName, "admin");
+    assert_eq!(*admin, "admin");
+    let tag = holda_lit!(Tag, "anything goes");
+    assert_eq!(*tag, "anything goes");
+}
+
+#[test]
+fn test_const_literal() {
+    assert_eq!(*HTTP, 80);
+}
+
+#[test]
+fn test_invalid_literals_fail_to_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/literal_*.rs");
+}
@@ -0,0 +1,81 @@
+use holda::Holda;
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+struct Note {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(normalize(trim, lowercase))]
+struct Email {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(non_empty, max_len = 8)]
+struct UserName {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(range = 1..=100)]
+struct Percent {
+    inner: u8,
+}
+
+#[derive(StringHolda)]
+#[holda(NoDerefMut)]
+struct Title {
+    inner: String,
+}
+
+#[test]
+fn test_modify_in_place() {
+    let mut note = Note::new("hello");
+    let len = note.modify(|inner| {
+        inner.push_str(" world");
+        inner.len()
+    });
+    assert_eq!(len, 11);
+    assert_eq!(*note, "hello world");
+}
+
+#[test]
+fn test_modify_normalizes() {
+    let mut email = Email::new("bruh@example.com");
+    email.modify(|inner| inner.push_str("  .AU "));
+    assert_eq!(*email, "bruh@example.com  .au");
+}
+
+#[test]
+fn test_try_modify_validates() {
+    let mut user_name = UserName::try_new("bob").unwrap();
+    assert!(user_name.try_modify(|inner| inner.push_str("by")).is_ok());
+    assert_eq!(*user_name, "bobby");
+
+    let err = user_name.try_modify(|inner| inner.clear()).unwrap_err();
+    assert_eq!(err.kind(), holda::ValidationErrorKind::Empty);
+    assert_eq!(*user_name, "bobby");
+}
+
+#[test]
+fn test_try_modify_holda() {
+    let mut percent = Percent::try_new(50u8).unwrap();
+    assert_eq!(percent.try_modify(|inner| *inner += 25), Ok(()));
+    assert!(percent.try_modify(|inner| *inner = 0).is_err());
+    assert_eq!(*percent, 75);
+}
+
+#[test]
+fn test_no_deref_mut_keeps_modify() {
+    let mut title = Title::new("draft");
+    title.modify(|inner| inner.make_ascii_uppercase());
+    assert_eq!(*title, "DRAFT");
+}
+
+#[test]
+fn test_mutation_is_controlled() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/mutation_*.rs");
+}
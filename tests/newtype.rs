@@ -0,0 +1,48 @@
+use std::mem::size_of;
+
+mod ids {
+    #[holda::newtype(Copy, range = 1..=65535)]
+    pub struct Port {
+        pub inner: u16,
+    }
+
+    #[holda::newtype(non_empty)]
+    pub struct UserName {
+        inner: String,
+    }
+
+    /// Identifiers of orders.
+    #[holda::newtype]
+    #[holda(NoDisplay)]
+    #[derive(Default)]
+    pub struct OrderId {
+        inner: uuid::Uuid,
+    }
+}
+
+use ids::OrderId;
+use ids::Port;
+use ids::UserName;
+
+#[test]
+fn test_newtype() {
+    let port = Port::try_new(8080u16).unwrap();
+    let copy = port;
+    assert_eq!(port, copy);
+    assert!(Port::try_new(0u16).is_err());
+    assert_eq!(size_of::<Port>(), size_of::<u16>());
+
+    #[cfg(feature = "std")]
+    assert_eq!(*"alice".parse::<UserName>().unwrap(), "alice");
+    assert!(UserName::try_new("").is_err());
+
+    let id = OrderId::default();
+    assert_eq!(*id, uuid::Uuid::nil());
+    assert_eq!(format!("{id:?}"), uuid::Uuid::nil().to_string());
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/newtype_*.rs");
+}
@@ -0,0 +1,101 @@
+use holda::Holda;
+use holda::StringHolda;
+use holda::holda_lit;
+use std::rc::Rc;
+
+#[derive(StringHolda)]
+#[holda(normalize(trim, lowercase))]
+struct Email {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(normalize(trim, collapse_whitespace), max_len = 8)]
+struct Title {
+    inner: Rc<str>,
+}
+
+fn strip_dashes(value: String) -> String {
+    value.replace('-', "")
+}
+
+#[derive(StringHolda)]
+#[holda(normalize(trim), normalize = strip_dashes, regex = "^[0-9]+$")]
+struct PhoneNumber {
+    inner: String,
+}
+
+fn round_to_ten(value: u32) -> u32 {
+    value / 10 * 10
+}
+
+#[derive(Holda)]
+#[holda(normalize = round_to_ten)]
+struct Bucket {
+    inner: u32,
+}
+
+#[test]
+fn test_every_construction_path_normalizes() {
+    assert_eq!(*Email::new(" Bob@Example.com "), "bob@example.com");
+    assert_eq!(*Email::from(" Bob@Example.com"), "bob@example.com");
+    assert_eq!(
+        *Email::from(String::from("BOB@EXAMPLE.COM ")),
+        "bob@example.com"
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        *"Bob@Example.com".parse::<Email>().unwrap(),
+        "bob@example.com"
+    );
+    assert_eq!(*Bucket::new(42u32), 40);
+}
+
+#[test]
+fn test_normalization_runs_before_validation() {
+    let title = Title::try_new("  a \t  b\n c  ").unwrap();
+    assert_eq!(&**title, "a b c");
+    assert!(Title::try_new("  a \t  b\n c d e f ").is_err());
+    assert_eq!(*PhoneNumber::try_new(" 555-0100 ").unwrap(), "5550100");
+}
+
+#[test]
+fn test_literal() {
+    assert_eq!(*holda_lit!(Email, "bob@example.com"), "bob@example.com");
+    assert_eq!(*holda_lit!(Bucket, 42), 40);
+    // Uppercase, but without a lowercase mapping.
+    assert_eq!(*holda_lit!(Email, "\u{1D400}"), "\u{1D400}");
+}
+
+#[test]
+fn test_literal_check_matches_lowercase() {
+    let mut buf = [0; 4];
+    for ch in '\0'..=char::MAX {
+        let value: &str = ch.encode_utf8(&mut buf);
+        assert_eq!(
+            holda::__private::normalize::is_lowercase(value),
+            value.to_lowercase() == value,
+            "{ch:?}"
+        );
+    }
+}
+
+#[cfg(feature = "nfc")]
+#[test]
+fn test_nfc() {
+    #[derive(StringHolda)]
+    #[holda(normalize(nfc))]
+    struct Name {
+        inner: String,
+    }
+
+    let decomposed = "Cafe\u{301}";
+    assert_eq!(*Name::new(decomposed), "Caf\u{e9}");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_normalizes() {
+    let email: Email = serde_json::from_str(r#"" Bob@Example.com ""#).unwrap();
+    assert_eq!(*email, "bob@example.com");
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::approx_constant)]
+
 mod tests {
     use holda::Holda;
 
@@ -0,0 +1,93 @@
+use holda::Holda;
+use holda::StringHolda;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+#[derive(StringHolda)]
+#[holda(only(Display, Eq, Hash, Serde))]
+struct Tag {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(preset = "id")]
+struct OrderId {
+    inner: Uuid,
+}
+
+#[derive(StringHolda)]
+#[holda(preset = "name")]
+struct UserName {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(preset = "quantity")]
+struct Quantity {
+    inner: u32,
+}
+
+#[derive(StringHolda)]
+#[holda(preset = "secret")]
+struct ApiKey {
+    inner: String,
+}
+
+#[test]
+fn test_only() {
+    let tags: HashSet<Tag> = [Tag::new("red"), Tag::new("red")].into_iter().collect();
+    assert_eq!(tags.len(), 1);
+    assert_eq!(Tag::new("red").to_string(), "red");
+    assert_eq!(Tag::new("red").into_inner(), "red");
+}
+
+#[test]
+fn test_id_preset() {
+    let uuid = Uuid::nil();
+    let id = OrderId::from(uuid);
+    assert_eq!(id.clone(), id);
+    assert!(id <= OrderId::new(uuid));
+    let _: Uuid = id.into();
+}
+
+#[test]
+fn test_name_preset() {
+    let name = UserName::from("alice");
+    assert_eq!(name.len(), 5);
+    assert_eq!(name.to_string(), "alice");
+}
+
+#[test]
+fn test_quantity_preset() {
+    let quantity = Quantity::new(3u32);
+    assert!(quantity < Quantity::new(4u32));
+    assert_eq!(*quantity + 1, 4);
+}
+
+#[test]
+fn test_quantity_preset_mutation() {
+    let mut quantity = Quantity::new(3u32);
+    quantity.modify(|inner| *inner += 1);
+    assert_eq!(*quantity.map_inner(|inner| inner * 2), 8);
+}
+
+#[test]
+fn test_secret_preset() {
+    let key = ApiKey::from("hunter2");
+    assert_eq!(format!("{key:?}"), "ApiKey(<redacted>)");
+    assert_eq!(key.clone(), key);
+    assert_eq!(key.into_inner(), "hunter2");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let tag: Tag = serde_json::from_str("\"red\"").unwrap();
+    assert_eq!(serde_json::to_string(&tag).unwrap(), "\"red\"");
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/preset_*.rs");
+}
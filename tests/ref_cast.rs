@@ -0,0 +1,58 @@
+use holda::Holda;
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(ref_cast)]
+#[repr(transparent)]
+pub struct Tag {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(ref_cast, range = 1..=65535)]
+#[repr(transparent)]
+pub struct Port {
+    inner: u16,
+}
+
+#[holda::newtype(ref_cast)]
+pub struct Count {
+    inner: u64,
+}
+
+#[test]
+fn test_ref_cast() {
+    let mut inner = String::from("rust");
+    let tag: &Tag = Tag::from_ref(&inner);
+    assert_eq!(**tag, "rust");
+
+    let tag = Tag::from_mut(&mut inner);
+    tag.push_str("acean");
+    assert_eq!(inner, "rustacean");
+
+    let inners = vec![String::from("a"), String::from("b")];
+    let tags: &[Tag] = Tag::from_slice(&inners);
+    assert_eq!(tags.len(), 2);
+    assert_eq!(*tags[1], "b");
+
+    const ZERO: &Count = Count::from_ref(&0);
+    assert_eq!(**ZERO, 0);
+}
+
+#[test]
+fn test_ref_cast_validated() {
+    let mut port = 8080u16;
+    assert_eq!(**Port::from_ref(&port).unwrap(), 8080);
+    assert_eq!(**Port::from_mut(&mut port).unwrap(), 8080);
+    assert!(Port::from_ref(&0).is_err());
+
+    assert_eq!(Port::from_slice(&[80, 443]).unwrap().len(), 2);
+    let err = Port::from_slice(&[80, 0, 443]).unwrap_err();
+    assert_eq!(err.type_name(), "Port");
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/ref_cast_*.rs");
+}
@@ -0,0 +1,72 @@
+#![cfg(all(feature = "smol_str", feature = "compact_str", feature = "heapless"))]
+
+use compact_str::CompactString;
+use holda::StringHolda;
+use smol_str::SmolStr;
+
+#[derive(StringHolda)]
+pub struct SmolName {
+    inner: SmolStr,
+}
+
+#[derive(StringHolda)]
+#[holda(non_empty, normalize(trim))]
+pub struct CompactName {
+    inner: CompactString,
+}
+
+#[derive(StringHolda)]
+pub struct InlineName {
+    inner: heapless::String<24>,
+}
+
+holda::define! {
+    pub Tag: SmolStr [max_len = 8];
+}
+
+#[test]
+fn test_smol_str() {
+    let name = SmolName::from("alice");
+    assert_eq!(name.as_str(), "alice");
+    assert!(!name.is_heap_allocated());
+    assert_eq!(name, "alice".parse().unwrap());
+    assert_eq!(name, SmolName::from(String::from("alice")));
+    assert_eq!(SmolName::from('x').as_str(), "x");
+    assert_eq!(name.to_string(), "alice");
+    assert_eq!(*holda::holda_lit!(SmolName, "bob"), "bob");
+
+    assert!(Tag::try_from("short").is_ok());
+    assert!(Tag::try_from("far too long").is_err());
+}
+
+#[test]
+fn test_compact_str() {
+    let name = CompactName::try_from("  bob  ").unwrap();
+    assert_eq!(name.as_str(), "bob");
+    assert!(!name.is_heap_allocated());
+    assert_eq!(name, "bob".parse().unwrap());
+    assert!(CompactName::try_from(" ").is_err());
+}
+
+#[test]
+fn test_inline() {
+    let name = InlineName::try_from("carol").unwrap();
+    assert_eq!(name.as_str(), "carol");
+    assert!(InlineName::try_from("a name that is longer than 24 bytes").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let smol = SmolName::from("alice");
+    let json = serde_json::to_string(&smol).unwrap();
+    assert_eq!(json, "\"alice\"");
+    assert_eq!(serde_json::from_str::<SmolName>(&json).unwrap(), smol);
+
+    let compact: CompactName = serde_json::from_str("\" bob \"").unwrap();
+    assert_eq!(compact.as_str(), "bob");
+    assert!(serde_json::from_str::<CompactName>("\"\"").is_err());
+
+    let inline: InlineName = serde_json::from_str("\"carol\"").unwrap();
+    assert_eq!(serde_json::to_string(&inline).unwrap(), "\"carol\"");
+}
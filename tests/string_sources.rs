@@ -0,0 +1,80 @@
+use holda::StringHolda;
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(StringHolda)]
+struct UserName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+struct Tag {
+    inner: Rc<str>,
+}
+
+#[derive(StringHolda)]
+struct Code {
+    inner: Box<str>,
+}
+
+#[derive(StringHolda)]
+struct Label {
+    inner: Cow<'static, str>,
+}
+
+#[derive(StringHolda)]
+#[holda(non_empty)]
+struct Title {
+    inner: Arc<str>,
+}
+
+#[test]
+fn test_sources() {
+    let owned = String::from("bob");
+    let ptr = owned.as_ptr();
+    let user_name = UserName::from(owned);
+    assert_eq!(user_name.as_ptr(), ptr);
+
+    let boxed: Box<str> = "bob".into();
+    let ptr = boxed.as_ptr();
+    assert_eq!(UserName::from(boxed).as_ptr(), ptr);
+
+    let borrowed = String::from("bob");
+    assert_eq!(*UserName::from(&borrowed), "bob");
+    assert_eq!(*UserName::from('b'), "b");
+    assert_eq!(*UserName::from(Cow::Borrowed("bob")), "bob");
+    assert_eq!(*UserName::from(Cow::Owned(String::from("bob"))), "bob");
+}
+
+#[test]
+fn test_sources_for_other_inners() {
+    assert_eq!(&**Tag::from(String::from("red")), "red");
+    assert_eq!(&**Tag::from('r'), "r");
+    assert_eq!(&**Tag::from(Cow::Borrowed("red")), "red");
+
+    let owned = String::with_capacity(3) + "abc";
+    let ptr = owned.as_ptr();
+    assert_eq!(Code::from(owned).as_ptr(), ptr);
+
+    assert_eq!(&**Label::from(String::from("new")), "new");
+    assert_eq!(&**Label::from(&String::from("new")), "new");
+}
+
+#[test]
+fn test_validated_sources() {
+    assert!(Title::try_from(String::from("Dune")).is_ok());
+    assert!(Title::try_from(String::new()).is_err());
+    assert!(Title::try_from(Cow::Borrowed("")).is_err());
+    assert!(Title::try_from('D').is_ok());
+}
+
+#[test]
+fn test_appends() {
+    let mut user_name = UserName::from("bob");
+    write!(user_name, "_{}", 42).unwrap();
+    user_name.extend(['_', 'x']);
+    user_name.extend(["_y", "_z"]);
+    assert_eq!(*user_name, "bob_42_x_y_z");
+}
@@ -0,0 +1,112 @@
+use holda::Holda;
+use holda::StringHolda;
+use holda::ValidationError;
+
+#[derive(StringHolda)]
+#[holda(normalize(trim))]
+struct Title {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(non_empty, max_len = 8)]
+struct UserName {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(NoEq, NoOrd, NoHash)]
+struct Celsius {
+    inner: f64,
+}
+
+#[derive(Holda)]
+#[holda(range = 0..=100)]
+struct Percent {
+    inner: u8,
+}
+
+#[derive(Holda)]
+#[holda(NoDisplay)]
+struct Limit {
+    inner: Option<u32>,
+}
+
+#[derive(Debug)]
+enum ParseError {
+    Invalid(ValidationError),
+    Overflow,
+}
+
+impl From<ValidationError> for ParseError {
+    fn from(err: ValidationError) -> Self {
+        ParseError::Invalid(err)
+    }
+}
+
+#[test]
+fn test_map_inner_normalizes() {
+    let title = Title::new("draft").map_inner(|inner| format!("  {inner} v2  "));
+    assert_eq!(*title, "draft v2");
+}
+
+#[test]
+fn test_map_inner_validates() {
+    let user_name = UserName::try_new("bob").unwrap();
+    let user_name = user_name.map_inner(|inner| inner.to_uppercase()).unwrap();
+    assert_eq!(*user_name, "BOB");
+    assert!(user_name.map_inner(|inner| inner.repeat(3)).is_err());
+}
+
+#[test]
+fn test_try_map_inner() {
+    let celsius = Celsius::new(20.0);
+    let celsius = celsius
+        .try_map_inner(|inner| Ok::<_, ()>(inner + 1.5))
+        .unwrap();
+    assert_eq!(*celsius, 21.5);
+    assert_eq!(celsius.try_map_inner(|_| Err("bad")).err(), Some("bad"));
+
+    let percent = Percent::try_new(60u8).unwrap();
+    let result = percent.try_map_inner(|inner| inner.checked_mul(2).ok_or(ParseError::Overflow));
+    assert!(matches!(result, Err(ParseError::Invalid(err)) if err.type_name() == "Percent"));
+    let percent = Percent::try_new(200u8 / 2).unwrap();
+    let result = percent.try_map_inner(|inner| inner.checked_mul(3).ok_or(ParseError::Overflow));
+    assert!(matches!(result, Err(ParseError::Overflow)));
+}
+
+#[test]
+fn test_map_into() {
+    let percent: Percent = Percent::try_new(42u8).unwrap();
+    let celsius = percent.map_into::<Celsius>(f64::from);
+    assert_eq!(*celsius, 42.0);
+
+    let title = Title::new("bob");
+    let user_name = title.map_into::<UserName>(|inner| inner).unwrap();
+    assert_eq!(*user_name, "bob");
+    let title = Title::new("");
+    assert!(title.map_into::<UserName>(|inner| inner).is_err());
+}
+
+#[test]
+fn test_replace_inner() {
+    let mut title = Title::new("draft");
+    assert_eq!(title.replace_inner(" final "), "draft");
+    assert_eq!(*title, "final");
+
+    let mut user_name = UserName::try_new("bob").unwrap();
+    assert_eq!(user_name.replace_inner("alice"), Ok(String::from("bob")));
+    assert!(user_name.replace_inner("").is_err());
+    assert_eq!(*user_name, "alice");
+}
+
+#[test]
+fn test_deref_methods_not_shadowed() {
+    let user_name = UserName::try_new("a-b").unwrap();
+    assert_eq!(user_name.replace("-", "_"), "a_b");
+
+    let mut limit = Limit::new(Some(3));
+    assert_eq!(limit.map(|value| value * 2), Some(6));
+    assert_eq!(limit.replace(5), Some(3));
+    assert_eq!(*limit, Some(5));
+}
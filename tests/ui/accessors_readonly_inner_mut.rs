@@ -0,0 +1,12 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(readonly)]
+struct UserName {
+    inner: String,
+}
+
+fn main() {
+    let mut user_name = UserName::new("bob");
+    user_name.inner_mut().clear();
+}
@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(non_empty, AsMut)]
+struct UserName {
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use holda::Holda;
+
+#[derive(Holda)]
+#[holda(auto)]
+struct Ratio {
+    inner: f64,
+}
+
+fn main() {
+    let ratio = Ratio::new(0.5);
+    let _ = std::collections::HashSet::from([ratio]);
+}
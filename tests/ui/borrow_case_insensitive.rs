@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(Borrow, case_insensitive = "ascii")]
+struct HeaderName {
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(borrowed = UserNameRef, case_insensitive = "ascii")]
+pub struct UserName {
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(borrowed = EmailRef, normalize(lowercase))]
+pub struct Email {
+    inner: String,
+}
+
+fn main() {
+    let _ = EmailRef::new("Alice@Example.com");
+}
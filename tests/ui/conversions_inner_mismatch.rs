@@ -0,0 +1,14 @@
+use holda::Holda;
+
+#[derive(Holda)]
+struct Celsius {
+    inner: u64,
+}
+
+#[derive(Holda)]
+#[holda(from(Celsius))]
+struct Kelvin {
+    inner: u32,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+struct RawEmail {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(from(RawEmail), non_empty)]
+struct VerifiedEmail {
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+holda::define! {
+    #[derive(Default)]
+    pub UserName: String [non_empty];
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+holda::define! {
+    pub UserName: String [max_len = "eight"];
+    pub Port: u16 [non_empty];
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(from_str_error = "anyhow")]
+struct UserName {
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(FromStr)]
+struct UserName {
+    inner: String,
+}
+
+fn main() {}
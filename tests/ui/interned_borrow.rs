@@ -0,0 +1,10 @@
+use holda::StringHolda;
+use holda::Symbol;
+
+#[derive(StringHolda)]
+#[holda(interned, Borrow)]
+pub struct Ident {
+    inner: Symbol,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(interned)]
+pub struct Ident {
+    inner: String,
+}
+
+fn main() {}
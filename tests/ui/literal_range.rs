@@ -0,0 +1,14 @@
+use holda::Holda;
+use holda::holda_lit;
+
+#[derive(Holda)]
+#[holda(range = 1..=1024)]
+struct Port {
+    inner: u16,
+}
+
+const PORT: Port = holda_lit!(Port, 8080);
+
+fn main() {
+    let _ = PORT;
+}
@@ -0,0 +1,12 @@
+use holda::StringHolda;
+use holda::holda_lit;
+
+#[derive(StringHolda)]
+#[holda(regex = "^[a-z]+$")]
+struct UserName {
+    inner: String,
+}
+
+fn main() {
+    let _ = holda_lit!(UserName, "Admin");
+}
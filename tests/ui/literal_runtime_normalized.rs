@@ -0,0 +1,16 @@
+use holda::StringHolda;
+use holda::holda_lit;
+
+fn strip(value: String) -> String {
+    value.replace('-', "")
+}
+
+#[derive(StringHolda)]
+#[holda(normalize = strip, regex = "^[0-9]+$")]
+struct Phone {
+    inner: String,
+}
+
+fn main() {
+    let _ = holda_lit!(Phone, "abc");
+}
@@ -0,0 +1,12 @@
+use holda::StringHolda;
+use holda::holda_lit;
+
+#[derive(StringHolda)]
+#[holda(normalize(trim, lowercase))]
+struct Email {
+    inner: String,
+}
+
+fn main() {
+    let _ = holda_lit!(Email, "ǅx@example.com");
+}
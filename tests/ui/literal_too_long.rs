@@ -0,0 +1,12 @@
+use holda::StringHolda;
+use holda::holda_lit;
+
+#[derive(StringHolda)]
+#[holda(max_len = 4)]
+struct UserName {
+    inner: String,
+}
+
+fn main() {
+    let _ = holda_lit!(UserName, "administrator");
+}
@@ -0,0 +1,12 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(NoDeref)]
+struct UserName {
+    inner: String,
+}
+
+fn main() {
+    let user_name = UserName::new("bob");
+    let _ = user_name.len();
+}
@@ -0,0 +1,12 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(non_empty)]
+struct UserName {
+    inner: String,
+}
+
+fn main() {
+    let mut user_name = UserName::try_new("bob").unwrap();
+    user_name.clear();
+}
@@ -0,0 +1,7 @@
+#[holda::newtype(non_empty)]
+#[derive(Default)]
+pub struct UserName {
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[holda::newtype]
+pub struct Pair(u8, u8);
+
+#[holda::newtype]
+pub struct Point {
+    x: u8,
+    y: u8,
+}
+
+#[holda::newtype]
+pub enum Either {
+    Left,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![deny(unused_must_use)]
+
+#[holda::newtype]
+pub struct Count {
+    inner: u8,
+}
+
+fn main() {
+    Count::new(1u8);
+}
@@ -0,0 +1,11 @@
+mod ids {
+    #[holda::newtype(range = 1..=65535)]
+    pub struct Port {
+        pub inner: u16,
+    }
+}
+
+fn main() {
+    let port = ids::Port::try_new(8080u16).unwrap();
+    let _ = port.inner;
+}
@@ -0,0 +1,21 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(preset = "email")]
+struct Email {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(only(Display, Debug))]
+struct Label {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(preset = "id", only(Display))]
+struct Code {
+    inner: String,
+}
+
+fn main() {}
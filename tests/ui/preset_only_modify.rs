@@ -0,0 +1,13 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(only(Display))]
+struct Tag {
+    inner: String,
+}
+
+fn main() {
+    let mut tag = Tag::new("red");
+    tag.modify(|inner| inner.push('!'));
+    let _ = tag.map_inner(|inner| inner);
+}
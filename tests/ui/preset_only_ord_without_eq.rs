@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(only(Display, Ord))]
+struct Rank {
+    inner: String,
+}
+
+fn main() {}
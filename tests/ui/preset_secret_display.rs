@@ -0,0 +1,12 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(preset = "secret")]
+struct ApiKey {
+    inner: String,
+}
+
+fn main() {
+    let key = ApiKey::new("hunter2");
+    println!("{key}");
+}
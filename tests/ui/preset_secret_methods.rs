@@ -0,0 +1,19 @@
+use holda::StringHolda;
+use std::fmt::Write;
+use std::str::FromStr;
+
+#[derive(StringHolda)]
+#[holda(preset = "secret")]
+struct ApiKey {
+    inner: String,
+}
+
+fn main() {
+    let mut key = ApiKey::new("hunter2");
+    key.inner_mut().push('!');
+    key.modify(|inner| inner.push('!'));
+    key.replace_inner("hunter3");
+    write!(key, "!").unwrap();
+    let _: &str = key.as_ref();
+    let _ = ApiKey::from_str("hunter2");
+}
@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(ref_cast)]
+pub struct Tag {
+    inner: String,
+}
+
+fn main() {}
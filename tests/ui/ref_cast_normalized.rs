@@ -0,0 +1,10 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(ref_cast, normalize(trim))]
+#[repr(transparent)]
+pub struct Tag {
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+mod accounts {
+    use holda::StringHolda;
+
+    #[derive(StringHolda)]
+    #[holda(constructor = "private", NoFrom)]
+    pub struct AccountId {
+        inner: String,
+    }
+}
+
+fn main() {
+    let _: accounts::AccountId = serde_json::from_str("\"acc_1\"").unwrap();
+}
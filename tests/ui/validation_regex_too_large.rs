@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(regex = "^(((a{500}){500}){500})$")]
+struct Nested {
+    inner: String,
+}
+
+fn main() {}
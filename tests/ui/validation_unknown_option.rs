@@ -0,0 +1,9 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(non_emtpy)]
+pub struct UserName {
+    inner: String,
+}
+
+fn main() {}
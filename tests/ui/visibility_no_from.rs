@@ -0,0 +1,12 @@
+use holda::StringHolda;
+
+#[derive(StringHolda)]
+#[holda(NoFrom)]
+struct AccountId {
+    inner: String,
+}
+
+fn main() {
+    let _ = AccountId::new("acc_1");
+    let _ = AccountId::from(String::from("acc_2"));
+}
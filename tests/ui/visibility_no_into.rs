@@ -0,0 +1,12 @@
+use holda::Holda;
+
+#[derive(Holda)]
+#[holda(NoInto)]
+struct Verified {
+    inner: bool,
+}
+
+fn main() {
+    let verified = Verified::new(true);
+    let _: bool = verified.into();
+}
@@ -0,0 +1,24 @@
+use std::str::FromStr;
+
+mod accounts {
+    use holda::StringHolda;
+
+    #[derive(StringHolda)]
+    #[holda(constructor = "private")]
+    pub struct AccountId {
+        inner: String,
+    }
+
+    pub fn open() -> AccountId {
+        AccountId::new("acc_1")
+    }
+}
+
+fn main() {
+    let _ = accounts::open();
+    let _ = accounts::AccountId::new("acc_2");
+    let _ = accounts::AccountId::from_str("acc_3");
+    let _ = accounts::AccountId::from("acc_4");
+    let _ = accounts::AccountId::from('x');
+    let _: accounts::AccountId = String::from("acc_5").into();
+}
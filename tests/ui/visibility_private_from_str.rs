@@ -0,0 +1,9 @@
+use holda::Holda;
+
+#[derive(Holda)]
+#[holda(constructor = "pub(crate)", FromStr)]
+pub struct Port {
+    inner: u16,
+}
+
+fn main() {}
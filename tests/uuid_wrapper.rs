@@ -1,3 +1,5 @@
+#![allow(clippy::single_component_path_imports)]
+
 mod tests {
     use holda::Holda;
     use uuid::Uuid;
@@ -0,0 +1,129 @@
+use holda::Holda;
+use holda::StringHolda;
+use holda::ValidationErrorKind;
+
+#[derive(StringHolda)]
+#[holda(non_empty, min_len = 3, max_len = 8, charset = "a-z0-9_")]
+struct UserName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(regex = r"^[a-z]+(\.[a-z]+)*@[a-z]+\.(com|org)$")]
+struct Email {
+    inner: String,
+}
+
+// Nested counts over an empty group compile to nothing.
+#[derive(StringHolda)]
+#[holda(regex = "^((((){500}){500}){500}){500}a$")]
+struct Letter {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(one_of("admin", "user"))]
+struct Role {
+    inner: String,
+}
+
+#[derive(Holda)]
+#[holda(range = 1..=1024)]
+struct Port {
+    inner: u16,
+}
+
+#[derive(Holda)]
+#[holda(one_of(1, 2, 4, 8), NoDisplay)]
+struct Width {
+    inner: u8,
+}
+
+#[test]
+fn test_length() {
+    assert!(UserName::try_new("bob").is_ok());
+    assert_eq!(
+        UserName::try_new("").unwrap_err().kind(),
+        ValidationErrorKind::Empty
+    );
+    assert_eq!(
+        UserName::try_new("ab").unwrap_err().kind(),
+        ValidationErrorKind::TooShort { min: 3, actual: 2 }
+    );
+    assert_eq!(
+        UserName::try_new("abcdefghi").unwrap_err().kind(),
+        ValidationErrorKind::TooLong { max: 8, actual: 9 }
+    );
+}
+
+#[test]
+fn test_charset() {
+    assert_eq!(
+        UserName::try_new("Bob").unwrap_err().kind(),
+        ValidationErrorKind::InvalidChar { ch: 'B' }
+    );
+    assert!(UserName::try_new("bob_42").is_ok());
+}
+
+#[test]
+fn test_regex() {
+    assert!(Email::try_new("john.doe@example.com").is_ok());
+    assert!(Email::try_new("jane@example.org").is_ok());
+    assert!(Email::try_new("jane@example.net").is_err());
+    assert!(Email::try_new("jane@@example.com").is_err());
+    assert!(Email::try_new(" jane@example.com").is_err());
+    assert!(Letter::try_new("a").is_ok());
+    assert!(Letter::try_new("aa").is_err());
+}
+
+#[test]
+fn test_one_of() {
+    assert!(Role::try_new("admin").is_ok());
+    assert!(Role::try_new("root").is_err());
+    assert!(Width::try_new(4u8).is_ok());
+    assert!(Width::try_new(3u8).is_err());
+}
+
+#[test]
+fn test_range() {
+    assert!(Port::try_new(80u16).is_ok());
+    assert_eq!(
+        Port::try_new(0u16).unwrap_err().kind(),
+        ValidationErrorKind::OutOfRange { range: "1..=1024" }
+    );
+    assert!(Port::try_new(8080u16).is_err());
+}
+
+#[test]
+fn test_conversions_validate() {
+    assert!(UserName::try_from("bob").is_ok());
+    assert!(UserName::try_from(String::from("x")).is_err());
+}
+
+// `StringHolda` only implements `FromStr` without `std` if `from_str_error`
+// is set.
+#[cfg(feature = "std")]
+#[test]
+fn test_from_str_validates() {
+    assert!("bob".parse::<UserName>().is_ok());
+    let err = "x".parse::<UserName>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid UserName: must be at least 3 characters long, got 1"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_validates() {
+    let user_name: UserName = serde_json::from_str(r#""bob""#).unwrap();
+    assert_eq!(*user_name, "bob");
+    assert!(serde_json::from_str::<UserName>(r#""Bob""#).is_err());
+    assert!(serde_json::from_str::<Port>("0").is_err());
+}
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/validation_*.rs");
+}
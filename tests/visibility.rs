@@ -0,0 +1,76 @@
+use holda::StringHolda;
+
+mod accounts {
+    use holda::StringHolda;
+
+    #[derive(StringHolda)]
+    #[holda(constructor = "private")]
+    pub struct AccountId {
+        inner: String,
+    }
+
+    #[derive(StringHolda)]
+    #[holda(constructor = "pub(crate)", non_empty)]
+    pub struct AccountName {
+        inner: String,
+    }
+
+    pub fn open(number: u32) -> AccountId {
+        AccountId::new(format!("acc_{number}"))
+    }
+}
+
+#[derive(StringHolda)]
+#[holda(non_empty, max_len = 8, new_unchecked)]
+struct UserName {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(max_len = 8, new_unchecked)]
+struct Nickname {
+    inner: String,
+}
+
+#[derive(StringHolda)]
+#[holda(NoInto)]
+struct Secret {
+    inner: String,
+}
+
+// SAFETY: the empty string is at most 8 characters long.
+const NO_NICKNAME: Nickname = unsafe { Nickname::new_unchecked(String::new()) };
+
+#[test]
+fn test_private_constructor() {
+    let id = accounts::open(7);
+    assert_eq!(*id, "acc_7");
+}
+
+#[test]
+fn test_crate_constructor() {
+    let name = accounts::AccountName::try_new("bob").unwrap();
+    assert_eq!(*name, "bob");
+}
+
+#[test]
+fn test_new_unchecked() {
+    // SAFETY: "bob" is non-empty and short enough.
+    let user_name = unsafe { UserName::new_unchecked(String::from("bob")) };
+    assert_eq!(*user_name, "bob");
+    assert!(NO_NICKNAME.is_empty());
+}
+
+#[test]
+fn test_no_into_keeps_from() {
+    let secret = Secret::from("hunter2");
+    assert_eq!(*secret, "hunter2");
+}
+
+#[test]
+fn test_restricted_construction_fails_to_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/visibility_*.rs");
+    #[cfg(feature = "serde")]
+    t.compile_fail("tests/ui/serde/visibility_*.rs");
+}